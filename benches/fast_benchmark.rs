@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use moore_neighborhood::dynamic;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for (range, dimensions) in [(1, 2), (1, 3), (8, 2), (3, 4), (2, 6)] {
+        c.bench_function(&format!("decoded d={dimensions}, r={range}"), |b| {
+            b.iter(|| dynamic::moore(black_box(range), black_box(dimensions)))
+        });
+        c.bench_function(&format!("fast d={dimensions}, r={range}"), |b| {
+            b.iter(|| dynamic::moore_fast(black_box(range), black_box(dimensions)))
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
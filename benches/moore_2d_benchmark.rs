@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use moore_neighborhood::{dynamic, generic_dimension};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for range in [2, 100, 300] {
+        c.bench_function(&format!("moore_2d r={range}"), |b| {
+            b.iter(|| dynamic::moore_2d(black_box(range)))
+        });
+        c.bench_function(&format!("generic dimension d=2, r={range}"), |b| {
+            b.iter(|| generic_dimension::moore::<isize, 2>(black_box(range)))
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
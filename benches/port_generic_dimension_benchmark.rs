@@ -3,13 +3,13 @@ use moore_neighborhood::generic_dimension;
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("ported generic d=3, r=1", |b| {
-        b.iter(|| generic_dimension::moore::<3>(black_box(1)))
+        b.iter(|| generic_dimension::moore::<isize, 3>(black_box(1)))
     });
     c.bench_function("ported generic d=2, r=1", |b| {
-        b.iter(|| generic_dimension::moore::<2>(black_box(1)))
+        b.iter(|| generic_dimension::moore::<isize, 2>(black_box(1)))
     });
     c.bench_function("ported generic d=2, r=2", |b| {
-        b.iter(|| generic_dimension::moore::<2>(black_box(2)))
+        b.iter(|| generic_dimension::moore::<isize, 2>(black_box(2)))
     });
 }
 
@@ -3,13 +3,13 @@ use moore_neighborhood::generic_full;
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("ported full generic d=3, r=1", |b| {
-        b.iter(|| generic_full::moore::<1, 3, 26>())
+        b.iter(generic_full::moore::<1, 3, 26>)
     });
     c.bench_function("ported full generic d=2, r=1", |b| {
-        b.iter(|| generic_full::moore::<1, 2, 8>())
+        b.iter(generic_full::moore::<1, 2, 8>)
     });
     c.bench_function("ported full generic d=2, r=2", |b| {
-        b.iter(|| generic_full::moore::<2, 2, 24>())
+        b.iter(generic_full::moore::<2, 2, 24>)
     });
 }
 
@@ -1,5 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Obtains the Moore neighborhood for a region of width `RANGE` for in the specified number of `DIMENSIONS`.
 /// The returned array has length `LENGTH`, which is determined as `(2*RANGE+1).pow(DIMENSIONS) - 1`.
 ///
@@ -58,160 +61,385 @@
 ///     assert_eq!(result, expected);
 /// }
 /// ```
+///
+/// The range and dimensions can be any constant expression:
+///
+/// ```rust
+/// use moore_neighborhood::moore;
+///
+/// const RANGE: u32 = 2;
+/// const DIMENSIONS: usize = 3;
+///
+/// fn main() {
+///     let result: [[isize; 3]; 124] = moore!(RANGE, DIMENSIONS);
+///     assert_eq!(result[0], [-2, -2, -2]);
+///
+///     let result: [[isize; 2]; 24] = moore!(RANGE * 2 - 2);
+///     assert_eq!(result[0], [-2, -2]);
+/// }
+/// ```
+///
+/// The macro expands to a constant expression, so it can initialize constants and statics,
+/// also in `no_std` crates without an allocator:
+///
+/// ```rust
+/// use moore_neighborhood::moore;
+///
+/// const NEIGHBORS: [[isize; 2]; 8] = moore!(1, 2);
+///
+/// fn main() {
+///     assert_eq!(NEIGHBORS[0], [-1, -1]);
+/// }
+/// ```
+///
+/// Including the center cell, here with a range of `1` in two dimensions:
+///
+/// ```rust
+/// use moore_neighborhood::moore;
+///
+/// fn main() {
+///     let result: [[isize; 2]; 9] = moore!(1, 2; with_center);
+///
+///     let expected = [
+///         [-1,-1], [ 0,-1], [ 1,-1],
+///         [-1, 0], [ 0, 0], [ 1, 0],
+///         [-1, 1], [ 0, 1], [ 1, 1]
+///     ];
+///
+///     assert_eq!(result, expected);
+/// }
+/// ```
 #[macro_export]
 macro_rules! moore {
-    ($range: tt, $dims: tt) => {{
-        const RANGE: u32 = $range;
-        const DIMS: usize = $dims;
-        const NUM_FIELDS: usize = ((2 * RANGE as usize + 1).pow(DIMS as u32) - 1);
-        $crate::generic_full::moore::<RANGE, DIMS, NUM_FIELDS>()
+    ($range: expr, $dims: expr; with_center) => {{
+        // Items are not hygienic, hence the prefixed names to avoid clashing with the caller's constants.
+        const __MOORE_RANGE: u32 = $range;
+        const __MOORE_DIMS: usize = $dims;
+        const __MOORE_NUM_FIELDS: usize = $crate::generic_full::moore_length(__MOORE_RANGE, __MOORE_DIMS) + 1;
+        $crate::generic_full::moore_with_center::<__MOORE_RANGE, __MOORE_DIMS, __MOORE_NUM_FIELDS>()
     }};
 
-    ($range: tt) => {{
-        const RANGE: u32 = $range;
-        const DIMS: usize = 2;
-        const NUM_FIELDS: usize = ((2 * RANGE as usize + 1).pow(DIMS as u32) - 1);
-        $crate::generic_full::moore::<RANGE, DIMS, NUM_FIELDS>()
+    ($range: expr, $dims: expr) => {{
+        const __MOORE_RANGE: u32 = $range;
+        const __MOORE_DIMS: usize = $dims;
+        const __MOORE_NUM_FIELDS: usize = $crate::generic_full::moore_length(__MOORE_RANGE, __MOORE_DIMS);
+        $crate::generic_full::moore::<__MOORE_RANGE, __MOORE_DIMS, __MOORE_NUM_FIELDS>()
     }};
 
-    () => {{
-        const RANGE: u32 = 1;
-        const DIMS: usize = 2;
-        const NUM_FIELDS: usize = ((2 * RANGE as usize + 1).pow(DIMS as u32) - 1);
-        $crate::generic_full::moore::<RANGE, DIMS, NUM_FIELDS>()
-    }};
+    ($range: expr) => {
+        $crate::moore!($range, 2)
+    };
+
+    () => {
+        $crate::moore!(1, 2)
+    };
 }
 
-/// Moore neighborhoods for dynamic ranges and dynamic dimensionality.
-#[cfg(feature = "std")]
-pub mod dynamic {
-    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`.
-    ///
-    /// ## Example
-    ///
-    /// ```rust
-    /// use moore_neighborhood::dynamic::moore;
-    ///
-    /// let result: Vec<Vec<isize>> = moore(1, 2);
-    ///
-    /// let expected = [
-    ///     [-1,-1], [ 0,-1], [ 1,-1],
-    ///     [-1, 0],          [ 1, 0],
-    ///     [-1, 1], [ 0, 1], [ 1, 1]
-    /// ];
-    ///
-    /// assert_eq!(result, expected);
-    /// ```
-    pub fn moore(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
-        let size: usize = range as usize * 2 + 1;
-        let length: usize = size.pow(dimensions) - 1;
-        let half_length = length / 2;
-        let mut neighbors = Vec::with_capacity(length as _);
+/// Asserts at compile time that `LENGTH` is the number of neighbors of the Moore neighborhood for a region
+/// of width `RANGE` in the specified number of `DIMENSIONS`, i.e. the `LENGTH` expected by `generic_full::moore`.
+/// Unlike the debug assertion of `generic_full::moore`, a mismatch fails the build.
+///
+/// The macro expands to an item and needs to be invoked with constant expressions that do not depend
+/// on generic parameters, e.g. literals or named `const` items.
+///
+/// ## Example
+///
+/// ```rust
+/// use moore_neighborhood::{assert_moore_length, generic_full};
+///
+/// const RANGE: u32 = 1;
+/// const DIMENSIONS: usize = 3;
+/// const LENGTH: usize = 26;
+///
+/// assert_moore_length!(RANGE, DIMENSIONS, LENGTH);
+///
+/// let neighbors = generic_full::moore::<RANGE, DIMENSIONS, LENGTH>();
+/// assert_eq!(neighbors.len(), 26);
+/// ```
+///
+/// A wrong length is rejected by the compiler:
+///
+/// ```compile_fail
+/// use moore_neighborhood::assert_moore_length;
+///
+/// assert_moore_length!(1, 3, 27);
+/// ```
+#[macro_export]
+macro_rules! assert_moore_length {
+    ($range: expr, $dims: expr, $length: expr) => {
+        const _: () = assert!(
+            $length == $crate::generic_full::moore_length($range, $dims),
+            "LENGTH does not match the number of neighbors"
+        );
+    };
+}
 
-        for i in 0usize..length {
-            let mut neighbor = Vec::with_capacity(dimensions as _);
-            let mut index = if i < half_length { i } else { i + 1 };
-            let mut prev_divisor = 1;
-            for _dimension in 0..dimensions {
-                let divisor = prev_divisor * size;
-                let value = index % divisor;
-                neighbor.push((value / prev_divisor) as isize - range as isize);
-                prev_divisor = divisor;
-                index -= value;
+/// A signed integer type that can be used for the coordinates of a neighbor.
+///
+/// This is implemented for `i8`, `i16`, `i32`, `i64` and `isize`. When the `num-traits`
+/// feature is enabled, it is instead implemented for all signed primitive integers.
+pub trait Coord: Copy {
+    /// Converts the specified value into this type, truncating it if it does not fit.
+    fn from_isize(value: isize) -> Self;
+
+    /// Determines whether the specified value can be represented by this type.
+    fn fits(value: isize) -> bool;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {$(
+        #[cfg(not(feature = "num-traits"))]
+        impl Coord for $t {
+            #[inline]
+            fn from_isize(value: isize) -> Self {
+                value as _
             }
 
-            neighbors.push(neighbor);
+            #[inline]
+            fn fits(value: isize) -> bool {
+                <$t>::try_from(value).is_ok()
+            }
         }
-        neighbors
+    )*};
+}
+
+impl_coord!(i8, i16, i32, i64, isize);
+
+#[cfg(feature = "num-traits")]
+impl<T> Coord for T
+where
+    T: num_traits::PrimInt + num_traits::Signed + 'static,
+    isize: num_traits::AsPrimitive<T>,
+{
+    #[inline]
+    fn from_isize(value: isize) -> Self {
+        num_traits::AsPrimitive::as_(value)
     }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+    #[inline]
+    fn fits(value: isize) -> bool {
+        T::from(value).is_some()
+    }
+}
 
-        #[test]
-        fn dyn_d1_r1_works() {
-            let result = moore(1, 1);
-            let expected = [[-1], [1]];
-            assert_eq!(result, expected);
+/// The error type for fallible neighborhood operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MooreError {
+    /// The number of neighbors does not fit into a `usize`.
+    LengthOverflow,
+    /// The provided length does not match the number of neighbors.
+    LengthMismatch {
+        /// The number of neighbors.
+        expected: usize,
+        /// The provided length.
+        got: usize,
+    },
+    /// The neighborhood does not fit into a fixed-capacity buffer.
+    CapacityExceeded {
+        /// The required capacity.
+        required: usize,
+        /// The available capacity.
+        capacity: usize,
+    },
+}
+
+impl core::fmt::Display for MooreError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MooreError::LengthOverflow => write!(f, "the neighborhood length overflows usize"),
+            MooreError::LengthMismatch { expected, got } => {
+                write!(f, "expected a neighborhood length of {expected}, got {got}")
+            }
+            MooreError::CapacityExceeded { required, capacity } => {
+                write!(
+                    f,
+                    "a capacity of {required} is required, but only {capacity} is available"
+                )
+            }
         }
+    }
+}
 
-        #[test]
-        fn dyn_d2_r1_works() {
-            let result = moore(1, 2);
+#[cfg(feature = "std")]
+impl std::error::Error for MooreError {}
 
-            #[rustfmt::skip]
-                let expected = [
-                [-1,-1], [ 0,-1], [ 1,-1],
-                [-1, 0],          [ 1, 0],
-                [-1, 1], [ 0, 1], [ 1, 1]
-            ];
+/// Decodes the indexes of a Moore neighborhood into offsets.
+///
+/// The neighborhood of width `range` spans a region of `size = 2*range+1` cells in each of the `dimensions`.
+/// Every cell of the region is identified by its raster position, a mixed-radix number of base `size`
+/// whose digits are the coordinates shifted by `range`, with the first coordinate as the least significant
+/// digit. The neighbor indexes used throughout the crate skip the center cell at raster position
+/// `half_length`, so that index `half_length` refers to the cell right after the center.
+///
+/// ## Example
+///
+/// ```rust
+/// use moore_neighborhood::Decoder;
+///
+/// let decoder = Decoder::new(1, 2);
+/// assert_eq!(decoder.len(), 8);
+///
+/// assert!(decoder.decode(3).eq([-1, 0]));
+/// assert!(decoder.decode(4).eq([1, 0]));
+/// assert!(decoder.decode_cell(4).eq([0, 0]));
+/// assert_eq!(decoder.encode(&[1, 0]), Some(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decoder {
+    size: usize,
+    dimensions: usize,
+    half_length: usize,
+}
 
-            assert_eq!(result, expected);
+impl Decoder {
+    /// Creates a decoder for the Moore neighborhood of width `range` in the specified number of `dimensions`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of cells of the region does not fit into a `usize`.
+    pub const fn new(range: u32, dimensions: u32) -> Self {
+        let size: usize = range as usize * 2 + 1;
+        let cells = match size.checked_pow(dimensions) {
+            Some(cells) => cells,
+            None => panic!("the neighborhood length overflows usize"),
+        };
+
+        Self {
+            size,
+            dimensions: dimensions as _,
+            half_length: (cells - 1) / 2,
         }
+    }
 
-        #[test]
-        fn dyn_d3_r1_works() {
-            let result = moore(1, 3);
+    /// The number of cells of the region along each dimension, i.e. `2*range+1`.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
 
-            #[rustfmt::skip]
-            let expected = [
-                [-1,-1,-1], [ 0,-1,-1], [ 1,-1,-1],
-                [-1, 0,-1], [ 0, 0,-1], [ 1, 0,-1],
-                [-1, 1,-1], [ 0, 1,-1], [ 1, 1,-1],
+    /// The number of dimensions, i.e. the number of coordinates of each offset.
+    pub const fn dimensions(&self) -> usize {
+        self.dimensions
+    }
 
-                [-1,-1, 0], [ 0,-1, 0], [ 1,-1, 0],
-                [-1, 0, 0],             [ 1, 0, 0],
-                [-1, 1, 0], [ 0, 1, 0], [ 1, 1, 0],
+    /// The raster position of the center cell, which equals the number of neighbors before it.
+    pub const fn half_length(&self) -> usize {
+        self.half_length
+    }
 
-                [-1,-1, 1], [ 0,-1, 1], [ 1,-1, 1],
-                [-1, 0, 1], [ 0, 0, 1], [ 1, 0, 1],
-                [-1, 1, 1], [ 0, 1, 1], [ 1, 1, 1]
-            ];
+    /// The number of neighbors, excluding the center cell.
+    pub const fn len(&self) -> usize {
+        self.half_length * 2
+    }
 
-            assert_eq!(result, expected);
-        }
+    /// Determines whether the neighborhood is empty, i.e. whether the range is `0`.
+    pub const fn is_empty(&self) -> bool {
+        self.half_length == 0
+    }
 
-        #[test]
-        fn dyn_same_as_reference() {
-            let result = moore(3, 3);
-            let expected = reference(3, 3);
-            assert_eq!(result, expected);
+    /// Converts the neighbor `index` into the raster position of the cell, skipping the center cell.
+    pub const fn raster_index(&self, index: usize) -> usize {
+        if index < self.half_length {
+            index
+        } else {
+            index + 1
         }
+    }
 
-        fn reference(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
-            let size: usize = range as usize * 2 + 1;
-            let length: usize = size.pow(dimensions) - 1;
-            let mut neighbors = Vec::with_capacity(length as _);
+    /// Decodes the coordinates of the neighbor at `index`, skipping the center cell.
+    pub fn decode(&self, index: usize) -> impl Iterator<Item = isize> {
+        self.decode_cell(self.raster_index(index))
+    }
 
-            for i in 0usize..length {
-                let mut neighbor = Vec::with_capacity(dimensions as _);
-                let mut index = if i < length / 2 { i } else { i + 1 };
-                for dimension in 1..=dimensions {
-                    let value = index % size.pow(dimension as _);
-                    neighbor.push((value / size.pow(dimension - 1)) as isize - range as isize);
-                    index -= value;
-                }
+    /// Decodes the coordinates of the cell at raster position `raster`, including the center cell.
+    pub fn decode_cell(&self, mut raster: usize) -> impl Iterator<Item = isize> {
+        let size = self.size;
+        let range = (size / 2) as isize;
+        (0..self.dimensions).map(move |_| {
+            let coordinate = (raster % size) as isize - range;
+            raster /= size;
+            coordinate
+        })
+    }
 
-                neighbors.push(neighbor);
+    /// Decodes the coordinates of the neighbor at `index` into `neighbor`, skipping the center cell.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `neighbor` is shorter than the number of dimensions.
+    pub const fn decode_into(&self, index: usize, neighbor: &mut [isize]) {
+        self.decode_cell_into(self.raster_index(index), neighbor);
+    }
+
+    /// Decodes the coordinates of the cell at raster position `raster` into `neighbor`,
+    /// including the center cell.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `neighbor` is shorter than the number of dimensions.
+    pub const fn decode_cell_into(&self, mut raster: usize, neighbor: &mut [isize]) {
+        // Iterators are not available in const context, hence the explicit loop.
+        let range = (self.size / 2) as isize;
+        let mut dimension = 0;
+        while dimension < self.dimensions {
+            neighbor[dimension] = (raster % self.size) as isize - range;
+            raster /= self.size;
+            dimension += 1;
+        }
+    }
+
+    /// Encodes the coordinates of `cell` into its raster position, including the center cell,
+    /// i.e. the inverse of [`decode_cell()`](Self::decode_cell). Returns `None` if `cell` does not have
+    /// one coordinate per dimension or any of them lies outside of `-range..=range`.
+    pub const fn encode_cell(&self, cell: &[isize]) -> Option<usize> {
+        if cell.len() != self.dimensions {
+            return None;
+        }
+
+        // Horner's scheme from the slowest varying coordinate never exceeds the number of cells.
+        let range = (self.size / 2) as isize;
+        let mut raster = 0;
+        let mut dimension = self.dimensions;
+        while dimension > 0 {
+            dimension -= 1;
+            let coordinate = cell[dimension];
+            if coordinate < -range || coordinate > range {
+                return None;
             }
+            raster = raster * self.size + (coordinate + range) as usize;
+        }
+        Some(raster)
+    }
 
-            neighbors
+    /// Encodes the coordinates of `neighbor` into its index, skipping the center cell,
+    /// i.e. the inverse of [`decode()`](Self::decode). Returns `None` for the center cell and for
+    /// coordinates rejected by [`encode_cell()`](Self::encode_cell).
+    pub const fn encode(&self, neighbor: &[isize]) -> Option<usize> {
+        match self.encode_cell(neighbor) {
+            Some(raster) if raster < self.half_length => Some(raster),
+            Some(raster) if raster > self.half_length => Some(raster - 1),
+            _ => None,
         }
     }
 }
 
-/// Moore neighborhoods for dynamic ranges and statically known dimensionality.
-#[cfg(feature = "std")]
-pub mod generic_dimension {
-    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`.
+/// Moore neighborhoods for dynamic ranges and dynamic dimensionality.
+#[cfg(feature = "alloc")]
+pub mod dynamic {
+    use crate::generic_dimension::BoundaryPolicy;
+    use crate::{distance, Decoder, MooreError};
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ops::Index;
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`.
     ///
     /// ## Example
     ///
     /// ```rust
-    /// use moore_neighborhood::generic_dimension::moore;
+    /// use moore_neighborhood::dynamic::moore;
     ///
-    /// let result: Vec<[isize; 2]> = moore(1);
+    /// let result: Vec<Vec<isize>> = moore(1u32, 2);
     ///
     /// let expected = [
     ///     [-1,-1], [ 0,-1], [ 1,-1],
@@ -221,91 +449,6204 @@ pub mod generic_dimension {
     ///
     /// assert_eq!(result, expected);
     /// ```
-    pub fn moore<const DIMENSIONS: usize>(range: u32) -> Vec<[isize; DIMENSIONS]> {
-        assert!(DIMENSIONS < u32::MAX as _);
+    ///
+    /// The `range` can be given as any unsigned integer of up to 32 bits, e.g. a `u8` read from a configuration.
+    /// Untyped integer literals need a `u32` suffix, as they would otherwise default to `i32`:
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore;
+    ///
+    /// let range: u8 = 1;
+    /// assert_eq!(moore(range, 2), moore(1u32, 2));
+    /// ```
+    ///
+    /// A `range` of `0` yields an empty neighborhood for any number of `dimensions`.
+    ///
+    /// ## Ordering
+    ///
+    /// The neighbors are returned in scan order: the first coordinate varies fastest, the last coordinate
+    /// slowest, and the center cell is skipped. This order is part of the public API and is identical
+    /// to the one of `generic_dimension::moore` and `generic_full::moore`, so indexes into the
+    /// neighborhood stay valid across modules and releases.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of neighbors does not fit into a `usize`; see [`try_moore`].
+    pub fn moore(range: impl Into<u32>, dimensions: u32) -> Vec<Vec<isize>> {
+        match try_moore(range.into(), dimensions) {
+            Ok(neighbors) => neighbors,
+            Err(error) => panic!("{error}"),
+        }
+    }
 
-        let size: usize = range as usize * 2 + 1;
-        let length: usize = size.pow(DIMENSIONS as _) - 1;
-        let half_length = length / 2;
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// or [`MooreError::LengthOverflow`] if the number of neighbors does not fit into a `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, try_moore};
+    /// use moore_neighborhood::MooreError;
+    ///
+    /// assert_eq!(try_moore(1, 2), Ok(moore(1u32, 2)));
+    /// assert_eq!(try_moore(1, 100), Err(MooreError::LengthOverflow));
+    /// ```
+    pub fn try_moore(range: u32, dimensions: u32) -> Result<Vec<Vec<isize>>, MooreError> {
+        if range == 0 {
+            return Ok(Vec::new());
+        }
+
+        let length = moore_count_checked(range, dimensions).ok_or(MooreError::LengthOverflow)?;
+        let decoder = Decoder::new(range, dimensions);
         let mut neighbors = Vec::with_capacity(length as _);
 
         for i in 0usize..length {
-            let mut neighbor = [0; DIMENSIONS];
-            let mut index = if i < half_length { i } else { i + 1 };
-            let mut prev_divisor = 1;
-            for dimension in neighbor.iter_mut().take(DIMENSIONS) {
-                let divisor = prev_divisor * size;
-                let value = index % divisor;
-                *dimension = (value / prev_divisor) as isize - range as isize;
-                prev_divisor = divisor;
-                index -= value;
-            }
-
-            neighbors.push(neighbor);
+            neighbors.push(neighbor_at(&decoder, i));
         }
-        neighbors
+        Ok(neighbors)
     }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        #[test]
-        fn gen_dim_d2_r1_works() {
-            let result: Vec<[isize; 2]> = moore(1);
-
-            #[rustfmt::skip]
-            let expected = [
-                [-1, -1], [0, -1], [1, -1],
-                [-1, 0], [1, 0],
-                [-1, 1], [0, 1], [1, 1]
-            ];
+    /// The width of a Moore neighborhood, i.e. the largest Chebyshev distance of a neighbor from the center.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Range(pub u32);
 
-            assert_eq!(result, expected);
-        }
+    /// The number of dimensions of a Moore neighborhood.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Dimensions(pub u32);
 
-        #[test]
-        fn gen_dim_d2_r2_works() {
-            let result: Vec<[isize; 2]> = moore(2);
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// taking both as distinct types so that swapped arguments are rejected by the compiler.
+    /// The result is identical to [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_typed, Dimensions, Range};
+    ///
+    /// assert_eq!(moore_typed(Range(1), Dimensions(2)), moore(1u32, 2));
+    /// ```
+    ///
+    /// Swapping the arguments fails to compile:
+    ///
+    /// ```compile_fail
+    /// use moore_neighborhood::dynamic::{moore_typed, Dimensions, Range};
+    ///
+    /// moore_typed(Dimensions(2), Range(1));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of neighbors does not fit into a `usize`; see [`try_moore`].
+    pub fn moore_typed(range: Range, dimensions: Dimensions) -> Vec<Vec<isize>> {
+        moore(range.0, dimensions.0)
+    }
 
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// into `out`, replacing its previous contents. The capacity of `out` and of the neighbors already
+    /// stored in it is reused, so that refilling a buffer of the same shape does not allocate.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_into};
+    ///
+    /// let mut neighbors = Vec::new();
+    /// moore_into(1, 2, &mut neighbors);
+    /// assert_eq!(neighbors, moore(1u32, 2));
+    ///
+    /// moore_into(1, 3, &mut neighbors);
+    /// assert_eq!(neighbors, moore(1u32, 3));
+    /// ```
+    pub fn moore_into(range: u32, dimensions: u32, out: &mut Vec<Vec<isize>>) {
+        let decoder = Decoder::new(range, dimensions);
+        let length = decoder.len();
+        out.truncate(length);
+
+        for (i, neighbor) in out.iter_mut().enumerate() {
+            neighbor.resize(dimensions as _, 0);
+            decoder.decode_into(i, neighbor);
+        }
+
+        let reused = out.len();
+        out.extend((reused..length).map(|i| neighbor_at(&decoder, i)));
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// choosing the generation strategy based on the number of neighbors. The result is identical to [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_auto};
+    ///
+    /// assert_eq!(moore_auto(1, 2), moore(1u32, 2));
+    /// assert_eq!(moore_auto(5, 3), moore(5u32, 3));
+    /// ```
+    pub fn moore_auto(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        // Timed in release builds for ranges 1 to 20 and up to 8 dimensions: decoding every neighbor
+        // from its index and walking the region incrementally are within noise of each other for small
+        // neighborhoods, while from about a thousand neighbors on the incremental walk is 5-15% faster
+        // as it avoids a division per coordinate. Splitting a flat buffer (see `moore_flat`) into
+        // per-neighbor vectors was slower at all sizes, as the allocation per neighbor dominates.
+        const INCREMENTAL_THRESHOLD: usize = 1024;
+
+        match moore_count_checked(range, dimensions) {
+            Some(length) if length >= INCREMENTAL_THRESHOLD => moore_fast(range, dimensions),
+            _ => moore(range, dimensions),
+        }
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// by incrementing the coordinates like an odometer, with the first coordinate as the fastest-moving digit.
+    /// Unlike [`moore()`], no neighbor is decoded from its index, which avoids a division per coordinate.
+    /// The result is identical to [`moore()`].
+    ///
+    /// The incremental walk pays off from about a thousand neighbors on; below that, both are on par.
+    /// See [`moore_auto()`] to choose between them by size.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_fast};
+    ///
+    /// assert_eq!(moore_fast(1, 2), moore(1u32, 2));
+    /// assert_eq!(moore_fast(3, 4), moore(3u32, 4));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of neighbors does not fit into a `usize`.
+    pub fn moore_fast(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        let length = Decoder::new(range, dimensions).len();
+        let mut neighbors = Vec::with_capacity(length);
+        if length == 0 {
+            return neighbors;
+        }
+
+        let extent = range as isize;
+        let half_length = length / 2;
+        let mut cell = vec![-extent; dimensions as _];
+
+        for index in 0..=length {
+            if index != half_length {
+                neighbors.push(cell.clone());
+            }
+
+            for coordinate in cell.iter_mut() {
+                if *coordinate < extent {
+                    *coordinate += 1;
+                    break;
+                }
+                *coordinate = -extent;
+            }
+        }
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// generating the neighbors in parallel. The result is identical to [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_par};
+    ///
+    /// assert_eq!(moore_par(2, 3), moore(2u32, 3));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn moore_par(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        use rayon::prelude::*;
+
+        let decoder = Decoder::new(range, dimensions);
+
+        (0usize..decoder.len())
+            .into_par_iter()
+            .map(|i| neighbor_at(&decoder, i))
+            .collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// from a process-wide cache. The neighborhood is computed on the first call for each combination of
+    /// `range` and `dimensions`; subsequent calls return the same slice.
+    ///
+    /// Cached neighborhoods are never freed, so this should only be used for a small set of parameters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_cached};
+    ///
+    /// assert_eq!(moore_cached(1, 2), moore(1u32, 2));
+    /// assert!(core::ptr::eq(moore_cached(1, 2), moore_cached(1, 2)));
+    /// ```
+    #[cfg(feature = "cache")]
+    pub fn moore_cached(range: u32, dimensions: u32) -> &'static [Vec<isize>] {
+        use std::collections::HashMap;
+        use std::sync::{Mutex, OnceLock, PoisonError};
+
+        type Cache = Mutex<HashMap<(u32, u32), &'static [Vec<isize>]>>;
+        static CACHE: OnceLock<Cache> = OnceLock::new();
+
+        let mut cache = CACHE
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        cache
+            .entry((range, dimensions))
+            .or_insert_with(|| moore(range, dimensions).leak())
+    }
+
+    /// Obtains the two-dimensional Moore neighborhood for a region of width `range` with a nested loop over
+    /// both coordinates, which avoids decoding every neighbor from its index and is hence considerably faster
+    /// for large ranges. With the `simd` feature, each row is written four neighbors at a time using SIMD.
+    /// The result is identical to `generic_dimension::moore::<isize, 2>(range)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_2d;
+    ///
+    /// let result: Vec<[isize; 2]> = moore_2d(1);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_2d(range: u32) -> Vec<[isize; 2]> {
+        let extent = range as isize;
+
+        #[cfg(feature = "simd")]
+        let neighbors = {
+            let mut neighbors = vec![[0; 2]; moore_count(range, 2)];
+            let mut cells = neighbors.as_mut_slice();
+            for y in -extent..=extent {
+                cells = simd_row(cells, range, [0, y], y == 0);
+            }
+            neighbors
+        };
+
+        #[cfg(not(feature = "simd"))]
+        let neighbors = {
+            let mut neighbors = Vec::with_capacity(moore_count(range, 2));
+            for y in -extent..=extent {
+                for x in -extent..=extent {
+                    if x != 0 || y != 0 {
+                        neighbors.push([x, y]);
+                    }
+                }
+            }
+            neighbors
+        };
+
+        neighbors
+    }
+
+    /// Obtains the three-dimensional Moore neighborhood for a region of width `range` using SIMD to compute
+    /// the coordinates in batches. The result is identical to `generic_dimension::moore::<isize, 3>(range)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_3d;
+    ///
+    /// let result: Vec<[isize; 3]> = moore_3d(1);
+    /// assert_eq!(result.len(), 26);
+    /// assert_eq!(result[13], [1, 0, 0]);
+    /// ```
+    #[cfg(feature = "simd")]
+    pub fn moore_3d(range: u32) -> Vec<[isize; 3]> {
+        let extent = range as isize;
+        let mut neighbors = vec![[0; 3]; moore_count(range, 3)];
+        let mut cells = neighbors.as_mut_slice();
+        for z in -extent..=extent {
+            for y in -extent..=extent {
+                cells = simd_row(cells, range, [0, y, z], y == 0 && z == 0);
+            }
+        }
+        neighbors
+    }
+
+    /// Writes the row of neighbors `[x, rest[1], ..]` for `x` in `-range..=range` to the start of `cells`,
+    /// omitting `x == 0` if the row passes through the center, and returns the remaining cells.
+    #[cfg(feature = "simd")]
+    fn simd_row<const DIMENSIONS: usize>(
+        cells: &mut [[isize; DIMENSIONS]],
+        range: u32,
+        rest: [isize; DIMENSIONS],
+        skip_center: bool,
+    ) -> &mut [[isize; DIMENSIONS]] {
+        let extent = range as usize;
+        if skip_center {
+            let (before, cells) = cells.split_at_mut(extent);
+            let (after, cells) = cells.split_at_mut(extent);
+            simd_columns(before, -(range as isize), rest);
+            simd_columns(after, 1, rest);
+            cells
+        } else {
+            let (row, cells) = cells.split_at_mut(extent * 2 + 1);
+            simd_columns(row, -(range as isize), rest);
+            cells
+        }
+    }
+
+    /// Fills `cells` with the neighbors `[x + i, rest[1], ..]`. Four neighbors span `DIMENSIONS` vectors
+    /// of four lanes, which are stored at once; only the first lane of every neighbor advances between chunks.
+    #[cfg(feature = "simd")]
+    fn simd_columns<const DIMENSIONS: usize>(
+        cells: &mut [[isize; DIMENSIONS]],
+        x: isize,
+        rest: [isize; DIMENSIONS],
+    ) {
+        use wide::i64x4;
+
+        let mut vectors = [i64x4::ZERO; DIMENSIONS];
+        let mut steps = [i64x4::ZERO; DIMENSIONS];
+        for (k, (vector, step)) in vectors.iter_mut().zip(&mut steps).enumerate() {
+            let mut lanes = [0; 4];
+            let mut step_lanes = [0; 4];
+            for (l, (lane, step_lane)) in lanes.iter_mut().zip(&mut step_lanes).enumerate() {
+                let (cell, d) = ((4 * k + l) / DIMENSIONS, (4 * k + l) % DIMENSIONS);
+                if d == 0 {
+                    *lane = (x + cell as isize) as i64;
+                    *step_lane = 4;
+                } else {
+                    *lane = rest[d] as i64;
+                }
+            }
+            *vector = i64x4::new(lanes);
+            *step = i64x4::new(step_lanes);
+        }
+
+        let mut chunks = cells.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let flat = chunk.as_flattened_mut();
+            for ((out, vector), step) in flat.chunks_exact_mut(4).zip(&mut vectors).zip(steps) {
+                for (coordinate, lane) in out.iter_mut().zip(vector.to_array()) {
+                    *coordinate = lane as isize;
+                }
+                *vector = *vector + step;
+            }
+        }
+
+        let x = vectors[0].to_array()[0] as isize;
+        for (x, cell) in (x..).zip(chunks.into_remainder()) {
+            *cell = rest;
+            cell[0] = x;
+        }
+    }
+
+    /// Determines the number of neighbors in the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, i.e. `(2*range+1).pow(dimensions) - 1`, without generating it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics on overflow in debug builds; use [`moore_count_checked`] to handle large neighborhoods.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_count;
+    ///
+    /// assert_eq!(moore_count(1, 2), 8);
+    /// assert_eq!(moore_count(1, 3), 26);
+    /// ```
+    pub fn moore_count(range: u32, dimensions: u32) -> usize {
+        let size: usize = range as usize * 2 + 1;
+        size.pow(dimensions) - 1
+    }
+
+    /// Determines the number of neighbors in the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, returning `None` if the count does not fit into a `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_count_checked;
+    ///
+    /// assert_eq!(moore_count_checked(1, 2), Some(8));
+    /// assert_eq!(moore_count_checked(1, 100), None);
+    /// ```
+    pub fn moore_count_checked(range: u32, dimensions: u32) -> Option<usize> {
+        let size = (range as usize).checked_mul(2)?.checked_add(1)?;
+        Some(size.checked_pow(dimensions)? - 1)
+    }
+
+    /// Lazily obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`.
+    /// The neighbors are yielded in the same order as [`moore()`], but are only constructed on demand.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_iter;
+    ///
+    /// let mut neighbors = moore_iter(1, 2);
+    /// assert_eq!(neighbors.len(), 8);
+    ///
+    /// assert_eq!(neighbors.next(), Some(vec![-1, -1]));
+    /// assert_eq!(neighbors.len(), 7);
+    /// ```
+    pub fn moore_iter(range: u32, dimensions: u32) -> MooreIter {
+        let decoder = Decoder::new(range, dimensions);
+        MooreIter {
+            decoder,
+            index: 0,
+            length: decoder.len(),
+        }
+    }
+
+    /// An iterator over the Moore neighborhood of a region, as returned by [`moore_iter`].
+    ///
+    /// Cloning the iterator is cheap and yields an independent iterator continuing from the same position.
+    #[derive(Debug, Clone)]
+    pub struct MooreIter {
+        decoder: Decoder,
+        index: usize,
+        length: usize,
+    }
+
+    impl Iterator for MooreIter {
+        type Item = Vec<isize>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.index >= self.length {
+                return None;
+            }
+
+            let neighbor = neighbor_at(&self.decoder, self.index);
+            self.index += 1;
+            Some(neighbor)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.length - self.index;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl ExactSizeIterator for MooreIter {}
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// including the center cell at its natural position in the ordering.
+    /// The returned vector has length `(2*range+1).pow(dimensions)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_with_center;
+    ///
+    /// let result: Vec<Vec<isize>> = moore_with_center(1, 2);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0], [ 0, 0], [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_with_center(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        let decoder = Decoder::new(range, dimensions);
+        let length: usize = decoder.len() + 1;
+        let mut neighbors = Vec::with_capacity(length as _);
+
+        for index in 0usize..length {
+            let mut neighbor = vec![0; dimensions as _];
+            decoder.decode_cell_into(index, &mut neighbor);
+            neighbors.push(neighbor);
+        }
+        neighbors
+    }
+
+    /// Determines the position the center cell would occupy in the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, i.e. the number of neighbors preceding it in scan order.
+    /// Inserting the origin at this index into [`moore()`] yields [`moore_with_center()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{center_index, moore, moore_with_center};
+    ///
+    /// let mut neighbors = moore(1u32, 2);
+    /// assert_eq!(center_index(1, 2), 4);
+    ///
+    /// neighbors.insert(center_index(1, 2), vec![0, 0]);
+    /// assert_eq!(neighbors, moore_with_center(1, 2));
+    /// ```
+    pub fn center_index(range: u32, dimensions: u32) -> usize {
+        Decoder::new(range, dimensions).half_length()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// split at [`center_index()`] into the neighbors before and after the center. The halves mirror each other:
+    /// the `i`-th neighbor of the first half is the negation of the `i`-th last neighbor of the second half.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_halves;
+    ///
+    /// let (before, after) = moore_halves(1, 2);
+    /// assert_eq!(before, [[-1, -1], [0, -1], [1, -1], [-1, 0]]);
+    /// assert_eq!(after, [[1, 0], [-1, 1], [0, 1], [1, 1]]);
+    /// ```
+    pub fn moore_halves(range: u32, dimensions: u32) -> (Vec<Vec<isize>>, Vec<Vec<isize>>) {
+        let mut before = moore(range, dimensions);
+        let after = before.split_off(center_index(range, dimensions));
+        (before, after)
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// pairing each neighbor with its index in `0..moore_count(range, dimensions)`. The indexes follow the scan
+    /// order documented for [`moore()`] and are hence stable across releases.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_enumerated;
+    ///
+    /// let result = moore_enumerated(1, 2);
+    /// assert_eq!(result[0], (0, vec![-1, -1]));
+    /// assert_eq!(result[4], (4, vec![1, 0]));
+    /// assert_eq!(result[7], (7, vec![1, 1]));
+    /// ```
+    pub fn moore_enumerated(range: u32, dimensions: u32) -> Vec<(usize, Vec<isize>)> {
+        moore_iter(range, dimensions).enumerate().collect()
+    }
+
+    /// Obtains the cells at Chebyshev distance exactly `distance` from the center in the specified number of `dimensions`,
+    /// i.e. the outermost ring (or shell) of the Moore neighborhood of range `distance`.
+    /// The cells are returned in the same order as [`moore()`]; a `distance` of `0` yields no cells.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_shell;
+    ///
+    /// let result: Vec<Vec<isize>> = moore_shell(1, 2);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_shell(distance: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        moore_iter(distance, dimensions)
+            .filter(|neighbor| {
+                neighbor
+                    .iter()
+                    .any(|c| c.unsigned_abs() == distance as usize)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the successive shells in the specified number of `dimensions`,
+    /// yielding [`moore_shell()`] for the distances `1`, `2`, `3`, ... without end.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore_shell, shells};
+    ///
+    /// let mut shells = shells(2);
+    /// assert_eq!(shells.next(), Some(moore_shell(1, 2)));
+    /// assert_eq!(shells.next(), Some(moore_shell(2, 2)));
+    /// ```
+    pub fn shells(dimensions: u32) -> impl Iterator<Item = Vec<Vec<isize>>> {
+        (1..).map(move |distance| moore_shell(distance, dimensions))
+    }
+
+    /// Returns an iterator over the successive shells in the specified number of `dimensions`,
+    /// yielding [`moore_shell()`] for the distances `1..=max`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::shells_bounded;
+    ///
+    /// let sizes: Vec<usize> = shells_bounded(2, 3).map(|shell| shell.len()).collect();
+    /// assert_eq!(sizes, [8, 16, 24]);
+    /// ```
+    pub fn shells_bounded(dimensions: u32, max: u32) -> impl Iterator<Item = Vec<Vec<isize>>> {
+        (1..=max).map(move |distance| moore_shell(distance, dimensions))
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// partitioned into its shells: index `k - 1` holds the cells at Chebyshev distance `k`, i.e. [`moore_shell()`]
+    /// for distance `k`. The neighborhood is generated in a single pass.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore_by_shell, moore_shell};
+    ///
+    /// let result = moore_by_shell(2, 2);
+    /// assert_eq!(result.len(), 2);
+    /// assert_eq!(result[0], moore_shell(1, 2));
+    /// assert_eq!(result[1], moore_shell(2, 2));
+    /// ```
+    pub fn moore_by_shell(range: u32, dimensions: u32) -> Vec<Vec<Vec<isize>>> {
+        let mut shells = vec![Vec::new(); range as _];
+        for neighbor in moore_iter(range, dimensions) {
+            shells[distance::chebyshev(&neighbor) - 1].push(neighbor);
+        }
+        shells
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`.
+    /// The dimensionality is given by the length of `center`, and the center itself is not included.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_around;
+    ///
+    /// let result: Vec<Vec<isize>> = moore_around(&[0, 5], 1);
+    ///
+    /// let expected = [
+    ///     [-1, 4], [ 0, 4], [ 1, 4],
+    ///     [-1, 5],          [ 1, 5],
+    ///     [-1, 6], [ 0, 6], [ 1, 6]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_around(center: &[isize], range: u32) -> Vec<Vec<isize>> {
+        moore_iter(range, center.len() as _)
+            .map(|mut neighbor| {
+                for (coordinate, c) in neighbor.iter_mut().zip(center) {
+                    *coordinate += c;
+                }
+                neighbor
+            })
+            .collect()
+    }
+
+    /// Obtains the indexes of the cells within `range` of `center` on a ring buffer of length `len`, i.e.
+    /// `(center ± 1..=range) mod len` in the order of [`moore()`] for one dimension. When the range wraps around
+    /// the ring, i.e. `2 * range >= len`, every cell is returned only once and the center is never included.
+    /// The work is bounded by both `2 * range` and `len`, so neither a huge `range` nor a huge ring is expensive.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::ring_neighbors;
+    ///
+    /// assert_eq!(ring_neighbors(0, 2, 8), [6, 7, 1, 2]);
+    /// assert_eq!(ring_neighbors(1, 3, 4), [2, 3, 0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `len` is `0`.
+    pub fn ring_neighbors(center: usize, range: u32, len: usize) -> Vec<usize> {
+        assert!(len > 0, "len must be non-zero");
+
+        // Adds `a + b` modulo `len` for `a, b < len` without overflowing for rings close to `usize::MAX`.
+        let add = |a: usize, b: usize| if b < len - a { a + b } else { b - (len - a) };
+
+        let center = center % len;
+        let steps = (range as usize).min(len);
+        let start = match range as usize % len {
+            0 => center,
+            back => add(center, len - back),
+        };
+
+        // If both sides cover the ring, i.e. `2 * steps >= len - 1`, the first occurrences are the ring itself,
+        // beginning at `start`.
+        if steps >= len / 2 {
+            return (0..len)
+                .map(|i| add(start, i))
+                .filter(|&index| index != center)
+                .collect();
+        }
+
+        // Otherwise, the cells before and after the center cannot collide.
+        let mut neighbors = Vec::with_capacity(2 * steps);
+        neighbors.extend((0..steps).map(|i| add(start, i)));
+        neighbors.extend((1..=steps).map(|i| add(center, i)));
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region with a separate width per dimension,
+    /// i.e. dimension `d` spans `-ranges[d]..=ranges[d]`. The dimensionality is given by the length of `ranges`.
+    /// For uniform ranges this is identical to [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_anisotropic;
+    ///
+    /// let result: Vec<Vec<isize>> = moore_anisotropic(&[1, 0]);
+    /// assert_eq!(result, [[-1, 0], [1, 0]]);
+    /// ```
+    pub fn moore_anisotropic(ranges: &[u32]) -> Vec<Vec<isize>> {
+        let sizes: Vec<usize> = ranges.iter().map(|&r| r as usize * 2 + 1).collect();
+        let length: usize = sizes.iter().product();
+        let half_length = length / 2;
+        let mut neighbors = Vec::with_capacity(length - 1);
+
+        for i in (0..length).filter(|&i| i != half_length) {
+            let mut index = i;
+            let mut neighbor = Vec::with_capacity(ranges.len());
+            for (&range, &size) in ranges.iter().zip(&sizes) {
+                neighbor.push((index % size) as isize - range as isize);
+                index /= size;
+            }
+            neighbors.push(neighbor);
+        }
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// scanning the dimensions in the specified nesting order: `dim_order[0]` varies fastest and the last entry
+    /// of `dim_order` slowest. The identity permutation yields [`moore()`]; the reversed permutation scans
+    /// column-major grids in memory order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_ordered;
+    ///
+    /// let result = moore_ordered(1, 2, &[1, 0]);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [-1, 0], [-1, 1],
+    ///     [ 0,-1],          [ 0, 1],
+    ///     [ 1,-1], [ 1, 0], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `dim_order` is not a permutation of `0..dimensions`.
+    pub fn moore_ordered(range: u32, dimensions: u32, dim_order: &[usize]) -> Vec<Vec<isize>> {
+        let mut seen = vec![false; dimensions as _];
+        assert!(
+            dim_order.len() == seen.len()
+                && dim_order
+                    .iter()
+                    .all(|&d| d < seen.len() && !core::mem::replace(&mut seen[d], true)),
+            "dim_order must be a permutation of 0..dimensions"
+        );
+
+        // The k-th fastest digit of the scan becomes the coordinate of dimension dim_order[k].
+        moore_iter(range, dimensions)
+            .map(|digits| {
+                let mut neighbor = vec![0; digits.len()];
+                for (&d, digit) in dim_order.iter().zip(digits) {
+                    neighbor[d] = digit;
+                }
+                neighbor
+            })
+            .collect()
+    }
+
+    /// Obtains the Cartesian product of the per-dimension offsets in `axes`, excluding the center cell,
+    /// i.e. the all-zero offset if every axis contains `0`. The dimensionality is given by the length of `axes`,
+    /// and the first axis varies fastest like in [`moore()`]. For the axes `-range..=range` this is identical
+    /// to [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_custom;
+    ///
+    /// let result = moore_custom(&[&[-3, 0, 3], &[0, 1]]);
+    ///
+    /// let expected = [
+    ///     [-3, 0],          [ 3, 0],
+    ///     [-3, 1], [ 0, 1], [ 3, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_custom(axes: &[&[isize]]) -> Vec<Vec<isize>> {
+        let length: usize = axes.iter().map(|axis| axis.len()).product();
+        let mut neighbors = Vec::with_capacity(length);
+        if length == 0 {
+            return neighbors;
+        }
+
+        // Walk the product like an odometer over the positions within each axis.
+        let mut positions = vec![0; axes.len()];
+        for _ in 0..length {
+            let neighbor: Vec<isize> = axes
+                .iter()
+                .zip(&positions)
+                .map(|(axis, &position)| axis[position])
+                .collect();
+            if neighbor.iter().any(|&c| c != 0) {
+                neighbors.push(neighbor);
+            }
+
+            for (position, axis) in positions.iter_mut().zip(axes) {
+                *position += 1;
+                if *position < axis.len() {
+                    break;
+                }
+                *position = 0;
+            }
+        }
+        neighbors
+    }
+
+    /// Obtains the inclusive per-dimension bounds of the offsets of [`moore_anisotropic()`] for the specified `ranges`,
+    /// i.e. the minimum `-ranges[d]` and the maximum `ranges[d]` of every dimension `d`.
+    /// A dimension with a range of `0` spans only the coordinate `0`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_bounds;
+    ///
+    /// let (min, max) = moore_bounds(&[2, 1]);
+    /// assert_eq!(min, [-2, -1]);
+    /// assert_eq!(max, [2, 1]);
+    /// ```
+    pub fn moore_bounds(ranges: &[u32]) -> (Vec<isize>, Vec<isize>) {
+        let max: Vec<isize> = ranges.iter().map(|&range| range as isize).collect();
+        let min = max.iter().map(|&range| -range).collect();
+        (min, max)
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// as a single contiguous buffer, together with its stride (i.e. the number of `dimensions`).
+    /// The coordinates of the `i`-th neighbor are stored at `buffer[i * stride..(i + 1) * stride]`,
+    /// in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_flat;
+    ///
+    /// let (buffer, stride) = moore_flat(1, 2);
+    ///
+    /// let expected = [
+    ///     -1,-1,  0,-1,  1,-1,
+    ///     -1, 0,         1, 0,
+    ///     -1, 1,  0, 1,  1, 1
+    /// ];
+    ///
+    /// assert_eq!(stride, 2);
+    /// assert_eq!(buffer, expected);
+    /// ```
+    pub fn moore_flat(range: u32, dimensions: u32) -> (Vec<isize>, usize) {
+        let decoder = Decoder::new(range, dimensions);
+        let stride = dimensions as usize;
+        let mut buffer = vec![0; decoder.len() * stride];
+
+        if stride > 0 {
+            for (i, neighbor) in buffer.chunks_exact_mut(stride).enumerate() {
+                decoder.decode_into(i, neighbor);
+            }
+        }
+        (buffer, stride)
+    }
+
+    /// Obtains all offsets within the Euclidean distance `radius` from the center cell in the specified number
+    /// of `dimensions`, i.e. a disc in 2D or a ball in 3D. Offsets exactly on the boundary are included,
+    /// the center cell is not. The offsets are returned in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_ball;
+    ///
+    /// let result: Vec<Vec<isize>> = moore_ball(1.0, 2);
+    ///
+    /// let expected = [
+    ///              [ 0,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///              [ 0, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_ball(radius: f64, dimensions: u32) -> Vec<Vec<isize>> {
+        // Truncation equals flooring here; negative radii saturate to zero.
+        let range = radius as u32;
+        let radius_squared = radius * radius;
+        moore_iter(range, dimensions)
+            .filter(|neighbor| {
+                let distance_squared: isize = neighbor.iter().map(|c| c * c).sum();
+                distance_squared as f64 <= radius_squared
+            })
+            .collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// in a structure-of-arrays layout, i.e. the transpose of [`moore()`]: the outer `Vec` has one entry per
+    /// dimension, holding the corresponding coordinate of every neighbor in scan order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_soa;
+    ///
+    /// let result = moore_soa(1, 2);
+    ///
+    /// assert_eq!(result[0], [-1, 0, 1, -1, 1, -1, 0, 1]);
+    /// assert_eq!(result[1], [-1, -1, -1, 0, 0, 1, 1, 1]);
+    /// ```
+    pub fn moore_soa(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        let length = moore_count(range, dimensions);
+        let mut columns = vec![Vec::with_capacity(length); dimensions as _];
+
+        for_each_moore(range, dimensions, |neighbor| {
+            for (column, &coordinate) in columns.iter_mut().zip(neighbor) {
+                column.push(coordinate);
+            }
+        });
+        columns
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// as `i32` offsets, in the same order as [`moore()`], matching the coordinate type of most graphics APIs.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_i32;
+    ///
+    /// let result: Vec<Vec<i32>> = moore_i32(1, 2);
+    /// assert_eq!(result[0], [-1, -1]);
+    /// assert_eq!(result[4], [1, 0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// In debug builds, panics if `range` exceeds `i32::MAX`.
+    pub fn moore_i32(range: u32, dimensions: u32) -> Vec<Vec<i32>> {
+        debug_assert!(range <= i32::MAX as u32, "range exceeds i32::MAX");
+        moore_iter(range, dimensions)
+            .map(|neighbor| neighbor.into_iter().map(|c| c as i32).collect())
+            .collect()
+    }
+
+    /// Invokes `f` for every neighbor of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, in the same order as [`moore()`].
+    /// A single buffer is reused for all neighbors.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::for_each_moore;
+    ///
+    /// let mut count = 0;
+    /// for_each_moore(1, 3, |offset| {
+    ///     assert_eq!(offset.len(), 3);
+    ///     count += 1;
+    /// });
+    ///
+    /// assert_eq!(count, 26);
+    /// ```
+    pub fn for_each_moore(range: u32, dimensions: u32, mut f: impl FnMut(&[isize])) {
+        let decoder = Decoder::new(range, dimensions);
+        let mut neighbor = vec![0; dimensions as _];
+
+        for i in 0usize..decoder.len() {
+            decoder.decode_into(i, &mut neighbor);
+            f(&neighbor);
+        }
+    }
+
+    /// Counts the neighbors of the Moore neighborhood for a region of width `range` in the specified number
+    /// of `dimensions` for which `predicate` returns `true`, without collecting them.
+    /// A single buffer is reused for all neighbors, like in [`for_each_moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_count_where;
+    ///
+    /// // Only the strictly diagonal offsets.
+    /// let diagonal = moore_count_where(1, 3, |offset| offset.iter().all(|&c| c != 0));
+    /// assert_eq!(diagonal, 8);
+    /// ```
+    pub fn moore_count_where(
+        range: u32,
+        dimensions: u32,
+        predicate: impl Fn(&[isize]) -> bool,
+    ) -> usize {
+        let mut count = 0;
+        for_each_moore(range, dimensions, |neighbor| {
+            count += usize::from(predicate(neighbor));
+        });
+        count
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// as a set, for constant-time membership tests of offsets. The set never contains the center cell.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_hashset;
+    ///
+    /// let neighbors = moore_hashset(1, 2);
+    /// assert_eq!(neighbors.len(), 8);
+    /// assert!(neighbors.contains(&vec![1, -1]));
+    /// assert!(!neighbors.contains(&vec![0, 0]));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn moore_hashset(range: u32, dimensions: u32) -> std::collections::HashSet<Vec<isize>> {
+        moore_iter(range, dimensions).collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// in a random order determined by `rng`. A seeded `rng` yields a reproducible order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_shuffled};
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut result = moore_shuffled(1, 2, &mut SmallRng::seed_from_u64(42));
+    /// assert_eq!(result.len(), 8);
+    ///
+    /// result.sort();
+    /// let mut expected = moore(1u32, 2);
+    /// expected.sort();
+    /// assert_eq!(result, expected);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn moore_shuffled(
+        range: u32,
+        dimensions: u32,
+        rng: &mut impl rand::Rng,
+    ) -> Vec<Vec<isize>> {
+        use rand::seq::SliceRandom;
+
+        let mut neighbors = moore(range, dimensions);
+        neighbors.shuffle(rng);
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// into stack-backed [`ArrayVec`](arrayvec::ArrayVec)s holding up to `MAXD` coordinates per neighbor
+    /// and up to `MAXN` neighbors, in the same order as [`moore()`]. This function does not allocate.
+    ///
+    /// Returns [`MooreError::CapacityExceeded`] if `dimensions` exceeds `MAXD` or the number of
+    /// neighbors exceeds `MAXN`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_arrayvec};
+    /// use moore_neighborhood::MooreError;
+    ///
+    /// let result = moore_arrayvec::<3, 32>(1, 2).unwrap();
+    /// assert_eq!(result.len(), 8);
+    /// assert_eq!(result[0].as_slice(), [-1, -1]);
+    ///
+    /// assert_eq!(
+    ///     moore_arrayvec::<3, 32>(1, 4).unwrap_err(),
+    ///     MooreError::CapacityExceeded { required: 4, capacity: 3 }
+    /// );
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    pub fn moore_arrayvec<const MAXD: usize, const MAXN: usize>(
+        range: u32,
+        dimensions: u32,
+    ) -> Result<arrayvec::ArrayVec<arrayvec::ArrayVec<isize, MAXD>, MAXN>, MooreError> {
+        if dimensions as usize > MAXD {
+            return Err(MooreError::CapacityExceeded {
+                required: dimensions as _,
+                capacity: MAXD,
+            });
+        }
+
+        let length = moore_count_checked(range, dimensions).ok_or(MooreError::LengthOverflow)?;
+        if length > MAXN {
+            return Err(MooreError::CapacityExceeded {
+                required: length,
+                capacity: MAXN,
+            });
+        }
+
+        let decoder = Decoder::new(range, dimensions);
+        Ok((0..length).map(|i| decoder.decode(i).collect()).collect())
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// retaining only the offsets for which `predicate` returns `true`. The offsets keep the order
+    /// of [`moore()`]; rejected offsets are never allocated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_filtered;
+    ///
+    /// // Only diagonal moves.
+    /// let result = moore_filtered(1, 2, |offset| offset.iter().all(|&c| c != 0));
+    /// assert_eq!(result, [[-1, -1], [1, -1], [-1, 1], [1, 1]]);
+    /// ```
+    pub fn moore_filtered(
+        range: u32,
+        dimensions: u32,
+        predicate: impl Fn(&[isize]) -> bool,
+    ) -> Vec<Vec<isize>> {
+        let mut neighbors = Vec::new();
+        for_each_moore(range, dimensions, |neighbor| {
+            if predicate(neighbor) {
+                neighbors.push(neighbor.to_vec());
+            }
+        });
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// retaining only the offsets with at most `max_nonzero` nonzero coordinates. In three dimensions with a range
+    /// of `1`, this yields the 6 face neighbors for `1`, the 18 face and edge neighbors for `2`, and the full
+    /// 26 neighbors for `3`. The offsets keep the order of [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_connectivity;
+    ///
+    /// assert_eq!(moore_connectivity(1, 3, 1).len(), 6);
+    /// assert_eq!(moore_connectivity(1, 3, 2).len(), 18);
+    /// assert_eq!(moore_connectivity(1, 3, 3).len(), 26);
+    /// ```
+    pub fn moore_connectivity(range: u32, dimensions: u32, max_nonzero: u32) -> Vec<Vec<isize>> {
+        moore_filtered(range, dimensions, |offset| {
+            offset.iter().filter(|&&c| c != 0).count() <= max_nonzero as usize
+        })
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// retaining only the purely diagonal offsets, i.e. those without any zero coordinate. In three dimensions
+    /// with a range of `1`, these are the 8 corners of the cube. The offsets keep the order of [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_diagonals;
+    ///
+    /// let result = moore_diagonals(1, 2);
+    /// assert_eq!(result, [[-1, -1], [1, -1], [-1, 1], [1, 1]]);
+    /// ```
+    pub fn moore_diagonals(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        moore_filtered(range, dimensions, |offset| offset.iter().all(|&c| c != 0))
+    }
+
+    /// Obtains one representative offset per symmetry class of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, where offsets are considered equivalent under sign flips
+    /// and permutations of the axes. The representatives are the offsets with sorted non-negative
+    /// coordinates `0 <= c0 <= c1 <= ... <= range`, in the same order as [`moore()`].
+    /// Use [`moore_orbit`] to expand a representative back to its symmetry class.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_canonical;
+    ///
+    /// assert_eq!(moore_canonical(1, 2), [[0, 1], [1, 1]]);
+    /// ```
+    pub fn moore_canonical(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        moore_filtered(range, dimensions, |offset| {
+            offset.windows(2).all(|pair| pair[0] <= pair[1])
+                && offset.first().is_none_or(|&c| c >= 0)
+        })
+    }
+
+    /// Obtains all offsets that are equivalent to `offset` under sign flips and permutations of the axes,
+    /// i.e. the symmetry class (or orbit) of the offset, in the same order as [`moore()`].
+    /// The orbit contains `offset` itself; the orbit of the center cell consists of the center cell only.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_orbit;
+    ///
+    /// assert_eq!(moore_orbit(&[0, 1]), [[0, -1], [-1, 0], [1, 0], [0, 1]]);
+    /// ```
+    pub fn moore_orbit(offset: &[isize]) -> Vec<Vec<isize>> {
+        let class = |offset: &[isize]| {
+            let mut magnitudes: Vec<usize> = offset.iter().map(|c| c.unsigned_abs()).collect();
+            magnitudes.sort_unstable();
+            magnitudes
+        };
+
+        let key = class(offset);
+        let range = distance::chebyshev(offset) as u32;
+        let mut orbit = moore_with_center(range, offset.len() as u32);
+        orbit.retain(|candidate| class(candidate) == key);
+        orbit
+    }
+
+    /// Obtains the differences between consecutive neighbors of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`. The first element is the first neighbor itself, and element `i`
+    /// is `neighbor[i] - neighbor[i - 1]`, so the prefix sums of the deltas are the neighbors of [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_deltas;
+    ///
+    /// let result = moore_deltas(1, 2);
+    /// assert_eq!(result[..4], [[-1, -1], [1, 0], [1, 0], [-2, 1]]);
+    /// ```
+    pub fn moore_deltas(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        let mut previous = vec![0; dimensions as _];
+        moore_iter(range, dimensions)
+            .map(|neighbor| {
+                let delta = neighbor.iter().zip(&previous).map(|(c, p)| c - p).collect();
+                previous = neighbor;
+                delta
+            })
+            .collect()
+    }
+
+    /// Computes a stable 64-bit fingerprint of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, e.g. to verify that two builds generate identical neighborhoods.
+    ///
+    /// The fingerprint is the 64-bit FNV-1a hash of the number of `dimensions` followed by all coordinates
+    /// in the order of [`moore()`], each encoded as a little-endian `i64`/`u64`. It is therefore identical
+    /// across platforms, pointer widths and releases.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_fingerprint;
+    ///
+    /// assert_eq!(moore_fingerprint(1, 2), 0xe851_7627_bd9c_a4f7);
+    /// assert_ne!(moore_fingerprint(1, 2), moore_fingerprint(2, 2));
+    /// ```
+    pub fn moore_fingerprint(range: u32, dimensions: u32) -> u64 {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, &u64::from(dimensions).to_le_bytes());
+        for_each_moore(range, dimensions, |neighbor| {
+            for &coordinate in neighbor {
+                hash = fnv1a(hash, &(coordinate as i64).to_le_bytes());
+            }
+        });
+        hash
+    }
+
+    /// The initial state of the 64-bit FNV-1a hash.
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+    /// Feeds `bytes` into the 64-bit FNV-1a `hash`.
+    fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        for &byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+        hash
+    }
+
+    /// A metric for the distance of an offset from the center cell.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DistanceMetric {
+        /// The maximum of the absolute coordinates.
+        Chebyshev,
+        /// The sum of the absolute coordinates.
+        Manhattan,
+        /// The sum of the squared coordinates.
+        EuclideanSquared,
+    }
+
+    impl DistanceMetric {
+        /// Determines the distance of the specified `offset` from the center cell.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use moore_neighborhood::dynamic::DistanceMetric;
+        ///
+        /// assert_eq!(DistanceMetric::Chebyshev.distance(&[-2, 1]), 2);
+        /// assert_eq!(DistanceMetric::Manhattan.distance(&[-2, 1]), 3);
+        /// assert_eq!(DistanceMetric::EuclideanSquared.distance(&[-2, 1]), 5);
+        /// ```
+        pub fn distance(&self, offset: &[isize]) -> usize {
+            match self {
+                DistanceMetric::Chebyshev => distance::chebyshev(offset),
+                DistanceMetric::Manhattan => distance::manhattan(offset),
+                DistanceMetric::EuclideanSquared => {
+                    offset.iter().map(|c| c.unsigned_abs().pow(2)).sum()
+                }
+            }
+        }
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// ordered by the distance from the center cell according to the specified `metric`, closest first.
+    /// Neighbors with the same distance keep the order of [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore_sorted, DistanceMetric};
+    ///
+    /// let result: Vec<Vec<isize>> = moore_sorted(1, 2, DistanceMetric::Manhattan);
+    ///
+    /// let expected = [
+    ///     [ 0,-1], [-1, 0], [ 1, 0], [ 0, 1],
+    ///     [-1,-1], [ 1,-1], [-1, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_sorted(range: u32, dimensions: u32, metric: DistanceMetric) -> Vec<Vec<isize>> {
+        let mut neighbors = moore(range, dimensions);
+        neighbors.sort_by_cached_key(|neighbor| metric.distance(neighbor));
+        neighbors
+    }
+
+    /// A compass direction in a two-dimensional grid. As in [`fmt::format_2d`](crate::fmt::format_2d),
+    /// the `y` axis points down, so that [`Direction::North`] is the offset `[0, -1]`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Direction {
+        /// The offset `[0, -1]`.
+        North,
+        /// The offset `[1, -1]`.
+        NorthEast,
+        /// The offset `[1, 0]`.
+        East,
+        /// The offset `[1, 1]`.
+        SouthEast,
+        /// The offset `[0, 1]`.
+        South,
+        /// The offset `[-1, 1]`.
+        SouthWest,
+        /// The offset `[-1, 0]`.
+        West,
+        /// The offset `[-1, -1]`.
+        NorthWest,
+    }
+
+    impl Direction {
+        /// Determines the unit offset of the direction.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use moore_neighborhood::dynamic::Direction;
+        ///
+        /// assert_eq!(Direction::North.offset(), [0, -1]);
+        /// assert_eq!(Direction::SouthEast.offset(), [1, 1]);
+        /// ```
+        pub fn offset(&self) -> [isize; 2] {
+            match self {
+                Direction::North => [0, -1],
+                Direction::NorthEast => [1, -1],
+                Direction::East => [1, 0],
+                Direction::SouthEast => [1, 1],
+                Direction::South => [0, 1],
+                Direction::SouthWest => [-1, 1],
+                Direction::West => [-1, 0],
+                Direction::NorthWest => [-1, -1],
+            }
+        }
+    }
+
+    /// Obtains the two-dimensional Moore neighborhood for a region of width `range` as an outward spiral.
+    /// Each ring is visited completely before the next one, clockwise and starting at the cell in the
+    /// `start` direction, i.e. `start.offset()` scaled by the distance of the ring.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore_spiral_2d, Direction};
+    ///
+    /// let result = moore_spiral_2d(1, Direction::North);
+    ///
+    /// assert_eq!(result, [
+    ///     [ 0,-1], [ 1,-1], [ 1, 0], [ 1, 1],
+    ///     [ 0, 1], [-1, 1], [-1, 0], [-1,-1]
+    /// ]);
+    /// ```
+    pub fn moore_spiral_2d(range: u32, start: Direction) -> Vec<[isize; 2]> {
+        let mut neighbors = Vec::with_capacity(moore_count(range, 2));
+
+        for distance in 1..=range as isize {
+            let [mut x, mut y] = start.offset().map(|c| c * distance);
+            for _ in 0..8 * distance {
+                neighbors.push([x, y]);
+
+                // Walk clockwise along the edges of the ring.
+                if y == -distance && x < distance {
+                    x += 1;
+                } else if x == distance && y < distance {
+                    y += 1;
+                } else if y == distance && x > -distance {
+                    x -= 1;
+                } else {
+                    y -= 1;
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// in a boustrophedon (reflected Gray code) order: the first coordinate varies fastest, and every
+    /// coordinate reverses its direction whenever a slower one advances. Consecutive neighbors therefore
+    /// differ by `±1` in exactly one dimension, except for the single larger step where the center is skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::moore_gray;
+    ///
+    /// let result = moore_gray(1, 2);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [ 1, 0],          [-1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_gray(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        if range == 0 {
+            return Vec::new();
+        }
+
+        let range = range as isize;
+        let mut path: Vec<Vec<isize>> = vec![Vec::with_capacity(dimensions as _)];
+
+        // Extend the path of the lower dimensions by one slower coordinate, walking it
+        // forwards and backwards in turn.
+        for _ in 0..dimensions {
+            let mut extended = Vec::with_capacity(path.len() * (2 * range + 1) as usize);
+            for (step, coordinate) in (-range..=range).enumerate() {
+                let lower: &mut dyn Iterator<Item = &Vec<isize>> = if step % 2 == 0 {
+                    &mut path.iter()
+                } else {
+                    &mut path.iter().rev()
+                };
+                extended.extend(lower.map(|cell| {
+                    let mut cell = cell.clone();
+                    cell.push(coordinate);
+                    cell
+                }));
+            }
+            path = extended;
+        }
+
+        path.retain(|cell| cell.iter().any(|&c| c != 0));
+        path
+    }
+
+    /// The sign of a single coordinate of an offset.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Sign {
+        /// The coordinate is less than zero.
+        Negative,
+        /// The coordinate is zero, i.e. the offset lies on the axis.
+        Zero,
+        /// The coordinate is greater than zero.
+        Positive,
+    }
+
+    impl Sign {
+        /// Determines the sign of the specified `coordinate`.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use moore_neighborhood::dynamic::Sign;
+        ///
+        /// assert_eq!(Sign::of(-3), Sign::Negative);
+        /// assert_eq!(Sign::of(0), Sign::Zero);
+        /// assert_eq!(Sign::of(2), Sign::Positive);
+        /// ```
+        pub fn of(coordinate: isize) -> Self {
+            match coordinate.signum() {
+                -1 => Sign::Negative,
+                0 => Sign::Zero,
+                _ => Sign::Positive,
+            }
+        }
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// grouped by the sign of each coordinate.
+    ///
+    /// Every offset is stored under its exact sign vector. Offsets with a zero coordinate therefore do not
+    /// belong to any orthant; they are grouped in the buckets whose sign vector contains [`Sign::Zero`].
+    /// Within each bucket, the offsets keep the order of [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore_by_sign, Sign};
+    ///
+    /// let result = moore_by_sign(2, 2);
+    /// assert_eq!(result.len(), 8);
+    /// assert_eq!(result[&vec![Sign::Positive, Sign::Positive]].len(), 4);
+    /// assert_eq!(result[&vec![Sign::Zero, Sign::Negative]], [[0, -2], [0, -1]]);
+    /// ```
+    pub fn moore_by_sign(range: u32, dimensions: u32) -> BTreeMap<Vec<Sign>, Vec<Vec<isize>>> {
+        let mut buckets: BTreeMap<Vec<Sign>, Vec<Vec<isize>>> = BTreeMap::new();
+        for neighbor in moore_iter(range, dimensions) {
+            let signs = neighbor.iter().map(|&c| Sign::of(c)).collect();
+            buckets.entry(signs).or_default().push(neighbor);
+        }
+        buckets
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// pairing each offset with the weight determined by `kernel`. See the [`kernels`] module
+    /// for common weighting functions.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{kernels, moore_weighted};
+    ///
+    /// let result = moore_weighted(2, 2, kernels::inverse_chebyshev);
+    /// assert_eq!(result[0], (vec![-2, -2], 1.0 / 3.0));
+    /// assert_eq!(result[6], (vec![-1, -1], 0.5));
+    /// ```
+    pub fn moore_weighted(
+        range: u32,
+        dimensions: u32,
+        kernel: impl Fn(&[isize]) -> f64,
+    ) -> Vec<(Vec<isize>, f64)> {
+        moore_iter(range, dimensions)
+            .map(|neighbor| {
+                let weight = kernel(&neighbor);
+                (neighbor, weight)
+            })
+            .collect()
+    }
+
+    /// Weighting functions for [`moore_weighted`].
+    pub mod kernels {
+        use crate::distance;
+
+        /// Weighs every offset equally with `1.0`.
+        pub fn uniform(_offset: &[isize]) -> f64 {
+            1.0
+        }
+
+        /// Weighs an offset with `1 / (1 + d)`, where `d` is its Chebyshev distance from the center cell.
+        pub fn inverse_chebyshev(offset: &[isize]) -> f64 {
+            1.0 / (1.0 + distance::chebyshev(offset) as f64)
+        }
+
+        /// Returns a kernel weighing an offset with the unnormalized Gaussian `exp(-r² / (2σ²))`,
+        /// where `r` is its Euclidean distance from the center cell.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use moore_neighborhood::dynamic::kernels::gaussian;
+        ///
+        /// let kernel = gaussian(1.0);
+        /// assert_eq!(kernel(&[0, 0]), 1.0);
+        /// assert_eq!(kernel(&[1, -1]), (-1.0f64).exp());
+        /// ```
+        #[cfg(feature = "std")]
+        pub fn gaussian(sigma: f64) -> impl Fn(&[isize]) -> f64 {
+            let denominator = 2.0 * sigma * sigma;
+            move |offset| {
+                let distance_squared: isize = offset.iter().map(|c| c * c).sum();
+                (-(distance_squared as f64) / denominator).exp()
+            }
+        }
+    }
+
+    /// Obtains the Moore neighborhoods for each of the specified `ranges` in the specified number of `dimensions`.
+    /// Each element is identical to the result of [`moore()`] for the corresponding range.
+    ///
+    /// Only the neighborhood of the largest range is generated; the smaller neighborhoods are
+    /// obtained from it by selecting the offsets within their range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_ranges};
+    ///
+    /// let result = moore_ranges(1..=3, 2);
+    /// assert_eq!(result, [moore(1u32, 2), moore(2u32, 2), moore(3u32, 2)]);
+    /// ```
+    pub fn moore_ranges(
+        ranges: impl IntoIterator<Item = u32>,
+        dimensions: u32,
+    ) -> Vec<Vec<Vec<isize>>> {
+        let ranges: Vec<u32> = ranges.into_iter().collect();
+        let largest = match ranges.iter().max() {
+            Some(&range) => moore(range, dimensions),
+            None => return Vec::new(),
+        };
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                let mut neighbors = Vec::with_capacity(moore_count(range, dimensions));
+                neighbors.extend(
+                    largest
+                        .iter()
+                        .filter(|neighbor| distance::chebyshev(neighbor) <= range as usize)
+                        .cloned(),
+                );
+                neighbors
+            })
+            .collect()
+    }
+
+    /// Obtains the offsets whose Chebyshev distance from the center cell lies in `inner+1..=outer`,
+    /// i.e. the Moore neighborhood of range `outer` without the Moore neighborhood of range `inner`.
+    /// The offsets are returned in the same order as [`moore()`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `inner` is larger than `outer`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_annulus};
+    ///
+    /// assert_eq!(moore_annulus(1, 2, 2).len(), 16);
+    /// assert_eq!(moore_annulus(0, 2, 2), moore(2u32, 2));
+    /// ```
+    pub fn moore_annulus(inner: u32, outer: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        assert!(
+            inner <= outer,
+            "inner range must not exceed the outer range"
+        );
+        moore_iter(outer, dimensions)
+            .filter(|neighbor| neighbor.iter().any(|c| c.unsigned_abs() > inner as usize))
+            .collect()
+    }
+
+    /// Obtains the set difference of the Moore neighborhoods of range `outer` and range `inner`,
+    /// i.e. the offsets of `moore(outer, dimensions)` that are not in `moore(inner, dimensions)`.
+    /// These are exactly the cells added when growing a neighborhood from `inner` to `outer`,
+    /// returned in the same order as [`moore()`]. The difference is empty if `inner` is not less than `outer`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::{moore, moore_difference};
+    ///
+    /// let inner = moore(1u32, 2);
+    /// let added = moore_difference(1, 2, 2);
+    ///
+    /// assert_eq!(added.len(), 16);
+    /// assert!(added.iter().all(|offset| !inner.contains(offset)));
+    /// assert!(moore_difference(2, 1, 2).is_empty());
+    /// ```
+    pub fn moore_difference(inner: u32, outer: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        // An offset is in moore(inner) if and only if its Chebyshev distance is at most inner.
+        moore_iter(outer, dimensions)
+            .filter(|neighbor| distance::chebyshev(neighbor) > inner as usize)
+            .collect()
+    }
+
+    /// Assembles a Moore neighborhood from chained options, as a single entry point to the variants
+    /// offered by [`moore_with_center()`], [`moore_connectivity()`] and
+    /// [`moore_bounded()`](crate::generic_dimension::moore_bounded). Options that are not set keep the behavior of [`moore()`]:
+    /// the center is excluded, every offset is retained and neighbors outside of the grid are dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::MooreBuilder;
+    /// use moore_neighborhood::generic_dimension::BoundaryPolicy;
+    ///
+    /// let result = MooreBuilder::new(1, 2)
+    ///     .include_center(true)
+    ///     .max_nonzero(Some(1))
+    ///     .boundary(BoundaryPolicy::Clamp)
+    ///     .build_at(&[0, 0], &[4, 4]);
+    ///
+    /// assert_eq!(result, [[0, 0], [0, 0], [0, 0], [1, 0], [0, 1]]);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MooreBuilder {
+        range: u32,
+        dimensions: u32,
+        include_center: bool,
+        max_nonzero: Option<u32>,
+        boundary: BoundaryPolicy,
+    }
+
+    impl MooreBuilder {
+        /// Creates a builder for the Moore neighborhood of width `range` in the specified number of `dimensions`.
+        pub fn new(range: u32, dimensions: u32) -> Self {
+            Self {
+                range,
+                dimensions,
+                include_center: false,
+                max_nonzero: None,
+                boundary: BoundaryPolicy::Drop,
+            }
+        }
+
+        /// Sets whether the center cell is included, like [`moore_with_center()`]. Defaults to `false`.
+        pub fn include_center(mut self, include_center: bool) -> Self {
+            self.include_center = include_center;
+            self
+        }
+
+        /// Sets the maximum number of nonzero coordinates of an offset, like [`moore_connectivity()`].
+        /// Defaults to `None`, retaining every offset.
+        pub fn max_nonzero(mut self, max_nonzero: Option<u32>) -> Self {
+            self.max_nonzero = max_nonzero;
+            self
+        }
+
+        /// Sets the treatment of neighbors outside of the grid by [`build_at()`](Self::build_at).
+        /// Defaults to [`BoundaryPolicy::Drop`].
+        pub fn boundary(mut self, boundary: BoundaryPolicy) -> Self {
+            self.boundary = boundary;
+            self
+        }
+
+        /// Obtains the offsets of the configured neighborhood in the order of [`moore()`].
+        pub fn build(&self) -> Vec<Vec<isize>> {
+            let offsets = if self.include_center {
+                moore_with_center(self.range, self.dimensions)
+            } else {
+                moore(self.range, self.dimensions)
+            };
+
+            match self.max_nonzero {
+                Some(max_nonzero) => offsets
+                    .into_iter()
+                    .filter(|offset| {
+                        offset.iter().filter(|&&c| c != 0).count() <= max_nonzero as usize
+                    })
+                    .collect(),
+                None => offsets,
+            }
+        }
+
+        /// Obtains the absolute coordinates of the configured neighborhood around the specified `center`
+        /// within a grid of the specified `bounds`, treating the neighbors outside of `0..bounds` according to
+        /// the configured [`boundary()`](Self::boundary) policy.
+        ///
+        /// ## Panics
+        ///
+        /// Panics if `center` or `bounds` do not have one entry per dimension, or if any of the `bounds` is `0`.
+        pub fn build_at(&self, center: &[isize], bounds: &[usize]) -> Vec<Vec<isize>> {
+            assert_eq!(
+                center.len(),
+                self.dimensions as usize,
+                "center must have one coordinate per dimension"
+            );
+            assert_eq!(
+                bounds.len(),
+                self.dimensions as usize,
+                "bounds must have one entry per dimension"
+            );
+
+            let mut neighbors = self.build();
+            for neighbor in neighbors.iter_mut() {
+                for (coordinate, c) in neighbor.iter_mut().zip(center) {
+                    *coordinate += c;
+                }
+            }
+
+            self.boundary.apply(&mut neighbors, bounds);
+            neighbors
+        }
+    }
+
+    /// The offsets of a neighborhood as an indexable collection, holding the same offsets in the same order as
+    /// the corresponding `Vec<Vec<isize>>` without exposing its representation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::dynamic::Neighborhood;
+    ///
+    /// let neighborhood = Neighborhood::moore(1, 2);
+    /// assert_eq!(neighborhood.len(), 8);
+    /// assert_eq!(neighborhood[0], [-1, -1]);
+    /// assert_eq!(neighborhood.get(7), Some(&[1, 1][..]));
+    /// assert_eq!(neighborhood.get(8), None);
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct Neighborhood {
+        dimensions: usize,
+        offsets: Vec<isize>,
+    }
+
+    impl Neighborhood {
+        /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`,
+        /// in the order of [`moore()`].
+        pub fn moore(range: u32, dimensions: u32) -> Self {
+            let decoder = Decoder::new(range, dimensions);
+            let mut offsets = vec![0; decoder.len() * decoder.dimensions()];
+            for (i, offset) in offsets
+                .chunks_exact_mut(decoder.dimensions().max(1))
+                .enumerate()
+            {
+                decoder.decode_into(i, offset);
+            }
+
+            Self {
+                dimensions: dimensions as _,
+                offsets,
+            }
+        }
+
+        /// Gets the number of dimensions of each offset.
+        pub fn dimensions(&self) -> usize {
+            self.dimensions
+        }
+
+        /// Gets the number of offsets.
+        pub fn len(&self) -> usize {
+            self.offsets.len().checked_div(self.dimensions).unwrap_or(0)
+        }
+
+        /// Determines whether the neighborhood holds no offsets.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Gets the offset at `index`, or `None` if `index` is out of bounds.
+        pub fn get(&self, index: usize) -> Option<&[isize]> {
+            if index < self.len() {
+                Some(&self.offsets[index * self.dimensions..][..self.dimensions])
+            } else {
+                None
+            }
+        }
+
+        /// Returns an iterator over the offsets.
+        pub fn iter(&self) -> impl Iterator<Item = &[isize]> + '_ {
+            (0..self.len()).map(move |index| &self[index])
+        }
+    }
+
+    impl Index<usize> for Neighborhood {
+        type Output = [isize];
+
+        fn index(&self, index: usize) -> &[isize] {
+            self.get(index).unwrap_or_else(|| {
+                panic!(
+                    "index out of bounds: the len is {} but the index is {index}",
+                    self.len()
+                )
+            })
+        }
+    }
+
+    /// Decodes the `i`-th neighbor of the neighborhood, skipping the center cell.
+    fn neighbor_at(decoder: &Decoder, i: usize) -> Vec<isize> {
+        let mut neighbor = vec![0; decoder.dimensions()];
+        decoder.decode_into(i, &mut neighbor);
+        neighbor
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn dyn_d1_r1_works() {
+            let result = moore(1u32, 1);
+            let expected = [[-1], [1]];
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_d2_r1_works() {
+            let result = moore(1u32, 2);
+
+            #[rustfmt::skip]
+                let expected = [
+                [-1,-1], [ 0,-1], [ 1,-1],
+                [-1, 0],          [ 1, 0],
+                [-1, 1], [ 0, 1], [ 1, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_d3_r1_works() {
+            let result = moore(1u32, 3);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-1,-1,-1], [ 0,-1,-1], [ 1,-1,-1],
+                [-1, 0,-1], [ 0, 0,-1], [ 1, 0,-1],
+                [-1, 1,-1], [ 0, 1,-1], [ 1, 1,-1],
+
+                [-1,-1, 0], [ 0,-1, 0], [ 1,-1, 0],
+                [-1, 0, 0],             [ 1, 0, 0],
+                [-1, 1, 0], [ 0, 1, 0], [ 1, 1, 0],
+
+                [-1,-1, 1], [ 0,-1, 1], [ 1,-1, 1],
+                [-1, 0, 1], [ 0, 0, 1], [ 1, 0, 1],
+                [-1, 1, 1], [ 0, 1, 1], [ 1, 1, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_iter_same_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 2), (1, 3), (2, 4)] {
+                let iter = moore_iter(range, dimensions);
+                let expected = moore(range, dimensions);
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+
+        #[test]
+        fn dyn_iter_len_decreases() {
+            let mut iter = moore_iter(1, 2);
+            for remaining in (0..8).rev() {
+                assert!(iter.next().is_some());
+                assert_eq!(iter.len(), remaining);
+            }
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn dyn_iter_clone_mid_iteration() {
+            let mut iter = moore_iter(1, 3);
+            let head: Vec<_> = iter.by_ref().take(10).collect();
+
+            let copy = iter.clone();
+            let tail: Vec<_> = iter.collect();
+            assert_eq!(copy.collect::<Vec<_>>(), tail);
+
+            let mut joined = head;
+            joined.extend(tail);
+            assert_eq!(joined, moore(1u32, 3));
+        }
+
+        #[test]
+        fn dyn_with_center_contains_origin_once() {
+            for (range, dimensions) in [(1, 1), (1, 2), (2, 2), (1, 3)] {
+                let mut result = moore_with_center(range, dimensions);
+                let size = 2 * range as usize + 1;
+                assert_eq!(result.len(), size.pow(dimensions));
+
+                let origin = vec![0; dimensions as usize];
+                let position = result.iter().position(|n| *n == origin).unwrap();
+                assert_eq!(result.iter().filter(|n| **n == origin).count(), 1);
+
+                result.remove(position);
+                assert_eq!(result, moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_enumerated_indexes() {
+            let result = moore_enumerated(2, 3);
+            assert_eq!(result.len(), 124);
+            assert_eq!(result[0], (0, vec![-2, -2, -2]));
+            assert_eq!(result[123], (123, vec![2, 2, 2]));
+
+            for (i, (index, neighbor)) in result.into_iter().enumerate() {
+                assert_eq!(index, i);
+                assert!(Decoder::new(2, 3).decode(index).eq(neighbor));
+            }
+
+            assert!(moore_enumerated(0, 2).is_empty());
+        }
+
+        #[test]
+        fn dyn_center_index_splices_origin() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 2), (1, 3), (2, 4)] {
+                let mut result = moore(range, dimensions);
+                result.insert(
+                    center_index(range, dimensions),
+                    vec![0; dimensions as usize],
+                );
+                assert_eq!(result, moore_with_center(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_moore_halves_mirror_each_other() {
+            for (range, dimensions) in [(1, 1), (1, 2), (2, 2), (1, 3), (0, 2)] {
+                let (before, after) = moore_halves(range, dimensions);
+                assert_eq!(before.len(), after.len());
+                assert_eq!(
+                    [before.clone(), after.clone()].concat(),
+                    moore(range, dimensions)
+                );
+
+                for (i, offset) in before.iter().enumerate() {
+                    let negated: Vec<isize> = offset.iter().map(|c| -c).collect();
+                    assert!(after.contains(&negated));
+                    assert_eq!(after[after.len() - 1 - i], negated);
+                }
+            }
+        }
+
+        #[test]
+        fn dyn_shell_d2_counts() {
+            for k in 1..5usize {
+                let expected = (2 * k + 1).pow(2) - (2 * k - 1).pow(2);
+                assert_eq!(moore_shell(k as _, 2).len(), expected);
+            }
+        }
+
+        #[test]
+        fn dyn_shell_distance_0_is_empty() {
+            assert!(moore_shell(0, 2).is_empty());
+            assert!(moore_shell(0, 3).is_empty());
+        }
+
+        #[test]
+        fn dyn_shells_union_is_moore() {
+            let mut union: Vec<_> = (1..=3).flat_map(|k| moore_shell(k, 3)).collect();
+            let mut expected = moore(3u32, 3);
+            union.sort();
+            expected.sort();
+            assert_eq!(union, expected);
+        }
+
+        #[test]
+        fn dyn_around_d2_r1_works() {
+            let result = moore_around(&[5, 5], 1);
+
+            #[rustfmt::skip]
+            let expected = [
+                [4, 4], [5, 4], [6, 4],
+                [4, 5],         [6, 5],
+                [4, 6], [5, 6], [6, 6]
+            ];
+
+            assert_eq!(result, expected);
+            assert!(!result.contains(&vec![5, 5]));
+        }
+
+        #[test]
+        fn dyn_around_may_go_negative() {
+            let result = moore_around(&[0, 0, 0], 1);
+            assert_eq!(result, moore(1u32, 3));
+        }
+
+        #[test]
+        fn dyn_count_matches_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 2), (1, 3), (2, 4)] {
+                let expected = moore(range, dimensions).len();
+                assert_eq!(moore_count(range, dimensions), expected);
+                assert_eq!(moore_count_checked(range, dimensions), Some(expected));
+            }
+        }
+
+        #[test]
+        fn dyn_count_checked_overflow() {
+            assert_eq!(moore_count_checked(1, usize::BITS), None);
+            assert_eq!(moore_count_checked(u32::MAX, 3), None);
+            assert_eq!(moore_count_checked(u32::MAX, 0), Some(0));
+        }
+
+        #[test]
+        fn dyn_sorted_manhattan_axis_first() {
+            let result = moore_sorted(2, 2, DistanceMetric::Manhattan);
+            assert_eq!(result.len(), 24);
+            assert_eq!(result[..4], [[0, -1], [-1, 0], [1, 0], [0, 1]]);
+            assert_eq!(result[20..], [[-2, -2], [2, -2], [-2, 2], [2, 2]]);
+        }
+
+        #[test]
+        fn dyn_sorted_chebyshev_is_stable() {
+            let result = moore_sorted(2, 2, DistanceMetric::Chebyshev);
+            let mut expected = moore_shell(1, 2);
+            expected.extend(moore_shell(2, 2));
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_sorted_euclidean_squared() {
+            let result = moore_sorted(2, 2, DistanceMetric::EuclideanSquared);
+            let distances: Vec<_> = result
+                .iter()
+                .map(|n| DistanceMetric::EuclideanSquared.distance(n))
+                .collect();
+            assert_eq!(distances[..8], [1, 1, 1, 1, 2, 2, 2, 2]);
+            assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        #[test]
+        fn dyn_anisotropic_d2_r2_r1() {
+            let result = moore_anisotropic(&[2, 1]);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-2,-1], [-1,-1], [ 0,-1], [ 1,-1], [ 2,-1],
+                [-2, 0], [-1, 0],          [ 1, 0], [ 2, 0],
+                [-2, 1], [-1, 1], [ 0, 1], [ 1, 1], [ 2, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_anisotropic_collapsed_dimension() {
+            let result = moore_anisotropic(&[1, 1, 0]);
+            let expected: Vec<_> = moore(1u32, 2)
+                .into_iter()
+                .map(|mut n| {
+                    n.push(0);
+                    n
+                })
+                .collect();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_anisotropic_uniform_is_moore() {
+            assert_eq!(moore_anisotropic(&[2, 2, 2]), moore(2u32, 3));
+        }
+
+        #[test]
+        fn dyn_ordered_identity_and_reversed_d2() {
+            assert_eq!(moore_ordered(2, 2, &[0, 1]), moore(2u32, 2));
+
+            let reversed = moore_ordered(2, 2, &[1, 0]);
+            let transposed: Vec<Vec<isize>> = moore(2u32, 2)
+                .into_iter()
+                .map(|n| vec![n[1], n[0]])
+                .collect();
+            assert_eq!(reversed, transposed);
+            assert_eq!(
+                reversed[..5],
+                [[-2, -2], [-2, -1], [-2, 0], [-2, 1], [-2, 2]]
+            );
+        }
+
+        #[test]
+        fn dyn_ordered_d3_permutation() {
+            let result = moore_ordered(1, 3, &[2, 0, 1]);
+            assert_eq!(result[0], [-1, -1, -1]);
+            assert_eq!(result[1], [-1, -1, 0]);
+            assert_eq!(result[3], [0, -1, -1]);
+            assert_eq!(result[9], [-1, 0, -1]);
+
+            let mut sorted = result.clone();
+            let mut expected = moore(1u32, 3);
+            sorted.sort();
+            expected.sort();
+            assert_eq!(sorted, expected);
+        }
+
+        #[test]
+        #[should_panic(expected = "dim_order must be a permutation of 0..dimensions")]
+        fn dyn_ordered_duplicate_dimension() {
+            moore_ordered(1, 2, &[1, 1]);
+        }
+
+        #[test]
+        #[should_panic(expected = "dim_order must be a permutation of 0..dimensions")]
+        fn dyn_ordered_wrong_length() {
+            moore_ordered(1, 3, &[0, 1]);
+        }
+
+        #[test]
+        fn dyn_custom_standard_is_moore() {
+            assert_eq!(moore_custom(&[&[-1, 0, 1], &[-1, 0, 1]]), moore(1u32, 2));
+            let axis: &[isize] = &[-2, -1, 0, 1, 2];
+            assert_eq!(moore_custom(&[axis; 3]), moore(2u32, 3));
+        }
+
+        #[test]
+        fn dyn_custom_asymmetric() {
+            let result = moore_custom(&[&[-3, 0, 3], &[-1, 0, 1]]);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-3,-1], [ 0,-1], [ 3,-1],
+                [-3, 0],          [ 3, 0],
+                [-3, 1], [ 0, 1], [ 3, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_custom_without_zero_keeps_all() {
+            let result = moore_custom(&[&[1, 2], &[-5]]);
+            assert_eq!(result, [[1, -5], [2, -5]]);
+
+            assert!(moore_custom(&[]).is_empty());
+            assert!(moore_custom(&[&[-1, 1], &[]]).is_empty());
+        }
+
+        #[test]
+        fn dyn_bounds_anisotropic_d2() {
+            let (min, max) = moore_bounds(&[2, 1]);
+            assert_eq!(min, [-2, -1]);
+            assert_eq!(max, [2, 1]);
+
+            let neighbors = moore_anisotropic(&[2, 1]);
+            for d in 0..2 {
+                assert_eq!(neighbors.iter().map(|n| n[d]).min(), Some(min[d]));
+                assert_eq!(neighbors.iter().map(|n| n[d]).max(), Some(max[d]));
+            }
+        }
+
+        #[test]
+        fn dyn_fingerprint_is_pinned() {
+            assert_eq!(moore_fingerprint(1, 1), 0x2adb_4174_beca_f63d);
+            assert_eq!(moore_fingerprint(1, 2), 0xe851_7627_bd9c_a4f7);
+            assert_eq!(moore_fingerprint(2, 3), 0x3809_dda4_f40f_c4f4);
+            assert_eq!(moore_fingerprint(0, 2), 0xe6bd_8644_3df8_ce07);
+        }
+
+        #[test]
+        fn dyn_fingerprint_distinguishes_parameters() {
+            let fingerprints: Vec<u64> = [(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2), (1, 3)]
+                .into_iter()
+                .map(|(range, dimensions)| moore_fingerprint(range, dimensions))
+                .collect();
+
+            for (i, a) in fingerprints.iter().enumerate() {
+                assert!(fingerprints[i + 1..].iter().all(|b| a != b));
+            }
+        }
+
+        #[test]
+        fn dyn_bounds_uniform() {
+            assert_eq!(moore_bounds(&[3; 4]), (vec![-3; 4], vec![3; 4]));
+            assert_eq!(moore_bounds(&[]), (vec![], vec![]));
+        }
+
+        #[test]
+        fn dyn_flat_reconstructs_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 2), (2, 3)] {
+                let (buffer, stride) = moore_flat(range, dimensions);
+                assert_eq!(stride, dimensions as usize);
+
+                let nested: Vec<Vec<isize>> = buffer.chunks(stride).map(|c| c.to_vec()).collect();
+                assert_eq!(nested, moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn dyn_par_same_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 2), (3, 3), (2, 5)] {
+                assert_eq!(moore_par(range, dimensions), moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "cache")]
+        fn dyn_cached_returns_same_slice() {
+            let first = moore_cached(2, 2);
+            let second = moore_cached(2, 2);
+            assert!(core::ptr::eq(first, second));
+            assert_eq!(first, moore(2u32, 2));
+
+            let other = moore_cached(1, 2);
+            assert!(!core::ptr::eq(first, other));
+            assert_eq!(other, moore(1u32, 2));
+        }
+
+        #[test]
+        fn dyn_by_sign_d2_r1() {
+            use Sign::*;
+
+            let result = moore_by_sign(1, 2);
+            assert_eq!(result.len(), 8);
+            assert!(!result.contains_key(&vec![Zero, Zero]));
+
+            for (signs, offset) in [
+                ([Negative, Negative], [-1, -1]),
+                ([Positive, Negative], [1, -1]),
+                ([Negative, Positive], [-1, 1]),
+                ([Positive, Positive], [1, 1]),
+                ([Zero, Negative], [0, -1]),
+                ([Negative, Zero], [-1, 0]),
+                ([Positive, Zero], [1, 0]),
+                ([Zero, Positive], [0, 1]),
+            ] {
+                assert_eq!(result[&signs.to_vec()], [offset]);
+            }
+        }
+
+        #[test]
+        fn dyn_by_sign_covers_neighborhood() {
+            let result = moore_by_sign(2, 3);
+            assert_eq!(result.len(), 26);
+
+            let mut flattened: Vec<Vec<isize>> = result.into_values().flatten().collect();
+            let mut expected = moore(2u32, 3);
+            flattened.sort();
+            expected.sort();
+            assert_eq!(flattened, expected);
+        }
+
+        #[test]
+        fn dyn_weighted_uniform_sums_to_count() {
+            let result = moore_weighted(1, 2, kernels::uniform);
+            let sum: f64 = result.iter().map(|(_, weight)| weight).sum();
+            assert_eq!(sum, 8.0);
+
+            let offsets: Vec<Vec<isize>> = result.into_iter().map(|(offset, _)| offset).collect();
+            assert_eq!(offsets, moore(1u32, 2));
+        }
+
+        #[test]
+        fn dyn_weighted_inverse_chebyshev() {
+            let result = moore_weighted(2, 2, kernels::inverse_chebyshev);
+            let sum: f64 = result.iter().map(|(_, weight)| weight).sum();
+            assert!((sum - (8.0 / 2.0 + 16.0 / 3.0)).abs() < 1e-12);
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn dyn_weighted_gaussian_decays() {
+            let kernel = kernels::gaussian(1.5);
+            assert!(kernel(&[1, 0]) > kernel(&[1, 1]));
+            assert!(kernel(&[1, 1]) > kernel(&[2, 0]));
+            assert_eq!(kernel(&[-1, 2]), kernel(&[2, 1]));
+        }
+
+        #[test]
+        fn dyn_ranges_same_as_moore() {
+            for dimensions in 1..=3 {
+                let ranges = [3, 0, 1, 2, 1];
+                let result = moore_ranges(ranges, dimensions);
+                assert_eq!(result.len(), ranges.len());
+                for (neighbors, range) in result.into_iter().zip(ranges) {
+                    assert_eq!(neighbors, moore(range, dimensions));
+                }
+            }
+        }
+
+        #[test]
+        fn dyn_ranges_empty() {
+            assert!(moore_ranges([], 2).is_empty());
+        }
+
+        #[test]
+        fn dyn_shells_flatten_to_moore() {
+            for dimensions in 1..=3 {
+                let mut result: Vec<Vec<isize>> = shells(dimensions).take(2).flatten().collect();
+                let mut expected = moore(2u32, dimensions);
+                result.sort();
+                expected.sort();
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[test]
+        fn dyn_by_shell_d2_r2() {
+            let result = moore_by_shell(2, 2);
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0].len(), 8);
+            assert_eq!(result[1].len(), 16);
+
+            let mut flattened: Vec<Vec<isize>> = result.into_iter().flatten().collect();
+            let mut expected = moore(2u32, 2);
+            flattened.sort();
+            expected.sort();
+            assert_eq!(flattened, expected);
+        }
+
+        #[test]
+        fn dyn_by_shell_same_as_moore_shell() {
+            for (range, dimensions) in [(0, 2), (1, 1), (3, 2), (2, 3)] {
+                let result = moore_by_shell(range, dimensions);
+                assert_eq!(result.len(), range as usize);
+                for (distance, shell) in (1..).zip(result) {
+                    assert_eq!(shell, moore_shell(distance, dimensions));
+                }
+            }
+        }
+
+        #[test]
+        fn dyn_shells_bounded() {
+            let result: Vec<_> = shells_bounded(3, 3).collect();
+            assert_eq!(result.len(), 3);
+            for (distance, shell) in (1..).zip(result) {
+                assert_eq!(shell, moore_shell(distance, 3));
+            }
+            assert_eq!(shells_bounded(2, 0).count(), 0);
+        }
+
+        #[test]
+        fn dyn_filtered_axis_aligned_is_von_neumann() {
+            for dimensions in 1..=4 {
+                let result = moore_filtered(1, dimensions, |offset| {
+                    offset.iter().filter(|&&c| c != 0).count() == 1
+                });
+                assert_eq!(result, crate::von_neumann::neighborhood(1, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_filtered_keeps_order() {
+            assert_eq!(moore_filtered(2, 3, |_| true), moore(2u32, 3));
+            assert!(moore_filtered(2, 3, |_| false).is_empty());
+        }
+
+        #[test]
+        fn dyn_connectivity_d3_faces() {
+            let result = moore_connectivity(1, 3, 1);
+
+            #[rustfmt::skip]
+            let expected = [
+                [ 0, 0,-1],
+                [ 0,-1, 0], [-1, 0, 0], [ 1, 0, 0], [ 0, 1, 0],
+                [ 0, 0, 1]
+            ];
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_connectivity_d3_edges() {
+            let result = moore_connectivity(1, 3, 2);
+            assert_eq!(result.len(), 18);
+            assert!(result.iter().all(|offset| offset.contains(&0)));
+        }
+
+        #[test]
+        fn dyn_connectivity_d3_full() {
+            assert_eq!(moore_connectivity(1, 3, 3), moore(1u32, 3));
+            assert_eq!(moore_connectivity(1, 3, 10), moore(1u32, 3));
+            assert!(moore_connectivity(1, 3, 0).is_empty());
+        }
+
+        #[test]
+        fn dyn_diagonals_d2_r1() {
+            assert_eq!(moore_diagonals(1, 2), [[-1, -1], [1, -1], [-1, 1], [1, 1]]);
+        }
+
+        #[test]
+        fn dyn_diagonals_d3_r1() {
+            let result = moore_diagonals(1, 3);
+            assert_eq!(result.len(), 8);
+            assert!(result.iter().all(|n| n.iter().all(|c| c.abs() == 1)));
+
+            // Together with the offsets with a zero coordinate, the diagonals make up the whole neighborhood.
+            assert_eq!(
+                result.len() + moore_connectivity(1, 3, 2).len(),
+                moore_count(1, 3)
+            );
+            assert_eq!(moore_diagonals(2, 3).len(), 64);
+        }
+
+        #[test]
+        fn dyn_canonical_d2_r1() {
+            let canonical = moore_canonical(1, 2);
+            assert_eq!(canonical, [[0, 1], [1, 1]]);
+            assert_eq!(
+                moore_orbit(&canonical[1]),
+                [[-1, -1], [1, -1], [-1, 1], [1, 1]]
+            );
+        }
+
+        #[test]
+        fn dyn_canonical_d3_r1() {
+            let canonical = moore_canonical(1, 3);
+            assert_eq!(canonical, [[0, 0, 1], [0, 1, 1], [1, 1, 1]]);
+
+            let sizes: Vec<usize> = canonical.iter().map(|c| moore_orbit(c).len()).collect();
+            assert_eq!(sizes, [6, 12, 8]);
+        }
+
+        #[test]
+        fn dyn_orbits_partition_neighborhood() {
+            for (range, dimensions) in [(1, 2), (2, 2), (1, 3), (2, 3), (1, 4)] {
+                let mut result: Vec<Vec<isize>> = moore_canonical(range, dimensions)
+                    .iter()
+                    .flat_map(|c| moore_orbit(c))
+                    .collect();
+                result.sort();
+
+                let mut expected = moore(range, dimensions);
+                expected.sort();
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[test]
+        fn dyn_orbit_of_origin() {
+            assert_eq!(moore_orbit(&[0, 0]), [[0, 0]]);
+        }
+
+        #[test]
+        fn dyn_auto_same_as_moore() {
+            // Covers both sides of the switch to the incremental generation at 1024 neighbors.
+            for (range, dimensions) in [
+                (0, 3),
+                (1, 1),
+                (2, 4),
+                (1, 6),
+                (15, 2),
+                (16, 2),
+                (4, 3),
+                (5, 3),
+                (1, 7),
+                (2, 5),
+            ] {
+                assert_eq!(moore_auto(range, dimensions), moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_fast_same_as_moore() {
+            for (range, dimensions) in [
+                (0, 2),
+                (1, 0),
+                (1, 1),
+                (1, 2),
+                (2, 2),
+                (3, 3),
+                (16, 2),
+                (2, 5),
+                (1, 7),
+            ] {
+                assert_eq!(moore_fast(range, dimensions), moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_deltas_reconstruct_d2_r1() {
+            let deltas = moore_deltas(1, 2);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-1,-1], [ 1, 0], [ 1, 0],
+                [-2, 1],          [ 2, 0],
+                [-2, 1], [ 1, 0], [ 1, 0]
+            ];
+            assert_eq!(deltas, expected);
+
+            let mut position = vec![0, 0];
+            let reconstructed: Vec<Vec<isize>> = deltas
+                .iter()
+                .map(|delta| {
+                    position.iter_mut().zip(delta).for_each(|(p, d)| *p += d);
+                    position.clone()
+                })
+                .collect();
+            assert_eq!(reconstructed, moore(1u32, 2));
+        }
+
+        #[test]
+        fn dyn_deltas_empty() {
+            assert!(moore_deltas(0, 2).is_empty());
+            assert_eq!(moore_deltas(2, 3).len(), 124);
+        }
+
+        #[test]
+        #[cfg(feature = "rand")]
+        fn dyn_shuffled_same_set_as_moore() {
+            use rand::{rngs::SmallRng, SeedableRng};
+
+            let mut rng = SmallRng::seed_from_u64(7);
+            let mut result = moore_shuffled(2, 3, &mut rng);
+            let mut expected = moore(2u32, 3);
+            assert_ne!(result, expected);
+
+            result.sort();
+            expected.sort();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        #[cfg(feature = "rand")]
+        fn dyn_shuffled_is_deterministic() {
+            use rand::{rngs::SmallRng, SeedableRng};
+
+            let first = moore_shuffled(2, 2, &mut SmallRng::seed_from_u64(1234));
+            let second = moore_shuffled(2, 2, &mut SmallRng::seed_from_u64(1234));
+            assert_eq!(first, second);
+        }
+
+        #[test]
+        #[cfg(feature = "arrayvec")]
+        fn dyn_arrayvec_fits() {
+            let result = moore_arrayvec::<3, 26>(1, 3).unwrap();
+            let result: Vec<Vec<isize>> = result.iter().map(|n| n.to_vec()).collect();
+            assert_eq!(result, moore(1u32, 3));
+
+            assert!(moore_arrayvec::<2, 0>(0, 2).unwrap().is_empty());
+        }
+
+        #[test]
+        #[cfg(feature = "arrayvec")]
+        fn dyn_arrayvec_capacity_exceeded() {
+            assert_eq!(
+                moore_arrayvec::<3, 25>(1, 3).unwrap_err(),
+                MooreError::CapacityExceeded {
+                    required: 26,
+                    capacity: 25
+                }
+            );
+            assert_eq!(
+                moore_arrayvec::<2, 100>(1, 3).unwrap_err(),
+                MooreError::CapacityExceeded {
+                    required: 3,
+                    capacity: 2
+                }
+            );
+            assert_eq!(
+                moore_arrayvec::<8, 100>(1, 100).unwrap_err(),
+                MooreError::CapacityExceeded {
+                    required: 100,
+                    capacity: 8
+                }
+            );
+        }
+
+        #[test]
+        fn dyn_typed_same_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 2), (2, 1), (1, 3)] {
+                assert_eq!(
+                    moore_typed(Range(range), Dimensions(dimensions)),
+                    moore(range, dimensions)
+                );
+            }
+        }
+
+        #[test]
+        fn dyn_unsigned_ranges() {
+            assert_eq!(moore(1u8, 2), moore(1u32, 2));
+            assert_eq!(moore(2u16, 3), moore(2u32, 3));
+            assert_eq!(moore(1u8, 2), moore_fast(1, 2));
+        }
+
+        #[test]
+        fn dyn_into_reuses_buffer() {
+            let mut neighbors = Vec::new();
+            moore_into(2, 3, &mut neighbors);
+            assert_eq!(neighbors, moore(2u32, 3));
+
+            let capacity = neighbors.capacity();
+            let first = neighbors[0].as_ptr();
+            moore_into(2, 3, &mut neighbors);
+            assert_eq!(neighbors, moore(2u32, 3));
+            assert_eq!(neighbors.capacity(), capacity);
+            assert_eq!(neighbors[0].as_ptr(), first);
+        }
+
+        #[test]
+        fn dyn_into_changes_shape() {
+            let mut neighbors = vec![vec![7; 5]; 3];
+            moore_into(1, 2, &mut neighbors);
+            assert_eq!(neighbors, moore(1u32, 2));
+
+            moore_into(1, 1, &mut neighbors);
+            assert_eq!(neighbors, moore(1u32, 1));
+            assert!(neighbors.capacity() >= 8);
+
+            moore_into(0, 2, &mut neighbors);
+            assert!(neighbors.is_empty());
+        }
+
+        #[test]
+        fn dyn_soa_transposes_moore() {
+            for (range, dimensions) in [(1, 1), (1, 2), (2, 3), (1, 4)] {
+                let columns = moore_soa(range, dimensions);
+                assert_eq!(columns.len(), dimensions as usize);
+                assert!(columns
+                    .iter()
+                    .all(|c| c.len() == moore_count(range, dimensions)));
+
+                let rows: Vec<Vec<isize>> = (0..columns[0].len())
+                    .map(|i| columns.iter().map(|column| column[i]).collect())
+                    .collect();
+                assert_eq!(rows, moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_soa_range_0() {
+            let columns = moore_soa(0, 3);
+            assert_eq!(columns.len(), 3);
+            assert!(columns.iter().all(Vec::is_empty));
+        }
+
+        #[test]
+        fn dyn_i32_same_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 3)] {
+                let expected: Vec<Vec<i32>> = moore(range, dimensions)
+                    .into_iter()
+                    .map(|n| n.into_iter().map(|c| c as i32).collect())
+                    .collect();
+                assert_eq!(moore_i32(range, dimensions), expected);
+            }
+        }
+
+        #[test]
+        fn dyn_spiral_2d_inner_ring_first() {
+            let result = moore_spiral_2d(2, Direction::North);
+            assert_eq!(result.len(), 24);
+
+            #[rustfmt::skip]
+            let inner = [
+                [ 0,-1], [ 1,-1], [ 1, 0], [ 1, 1],
+                [ 0, 1], [-1, 1], [-1, 0], [-1,-1]
+            ];
+            assert_eq!(result[..8], inner);
+            assert_eq!(result[8], [0, -2]);
+            assert_eq!(result[9], [1, -2]);
+            assert_eq!(result[23], [-1, -2]);
+        }
+
+        #[test]
+        fn dyn_spiral_2d_start_direction() {
+            let result = moore_spiral_2d(2, Direction::SouthWest);
+            assert_eq!(result[..3], [[-1, 1], [-1, 0], [-1, -1]]);
+            assert_eq!(result[8], [-2, 2]);
+            assert_eq!(result[9], [-2, 1]);
+        }
+
+        #[test]
+        fn dyn_spiral_2d_same_set_as_moore() {
+            let directions = [
+                Direction::North,
+                Direction::NorthEast,
+                Direction::East,
+                Direction::SouthEast,
+                Direction::South,
+                Direction::SouthWest,
+                Direction::West,
+                Direction::NorthWest,
+            ];
+            let mut expected = moore(3u32, 2);
+            expected.sort();
+
+            for direction in directions {
+                let mut result: Vec<Vec<isize>> = moore_spiral_2d(3, direction)
+                    .iter()
+                    .map(|n| n.to_vec())
+                    .collect();
+                result.sort();
+                assert_eq!(result, expected);
+            }
+        }
+
+        /// Determines whether `a` and `b` differ by `±1` in exactly one dimension.
+        fn is_unit_step(a: &[isize], b: &[isize]) -> bool {
+            let mut differences = a.iter().zip(b).map(|(a, b)| (a - b).abs());
+            differences.clone().filter(|&d| d != 0).count() == 1 && differences.all(|d| d <= 1)
+        }
+
+        #[test]
+        fn dyn_gray_d2_r1_unit_steps() {
+            let result = moore_gray(1, 2);
+            assert_eq!(result.len(), 8);
+
+            let seams: Vec<usize> = (1..result.len())
+                .filter(|&i| !is_unit_step(&result[i - 1], &result[i]))
+                .collect();
+
+            // The only larger step is across the skipped center, from [1, 0] to [-1, 0].
+            assert_eq!(seams, [4]);
+            assert_eq!(result[3], [1, 0]);
+            assert_eq!(result[4], [-1, 0]);
+        }
+
+        #[test]
+        fn dyn_gray_at_most_one_seam() {
+            for (range, dimensions) in [(1, 1), (2, 2), (1, 3), (2, 3), (1, 4)] {
+                let result = moore_gray(range, dimensions);
+                let seams = (1..result.len())
+                    .filter(|&i| !is_unit_step(&result[i - 1], &result[i]))
+                    .count();
+                assert!(seams <= 1, "range {range}, dimensions {dimensions}");
+            }
+        }
+
+        #[test]
+        fn dyn_gray_same_set_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 3)] {
+                let mut result = moore_gray(range, dimensions);
+                let mut expected = moore(range, dimensions);
+                result.sort();
+                expected.sort();
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[test]
+        fn dyn_ball_d2() {
+            assert_eq!(moore_ball(1.0, 2).len(), 4);
+            assert_eq!(moore_ball(1.5, 2), moore(1u32, 2));
+            assert_eq!(moore_ball(0.5, 2).len(), 0);
+            assert_eq!(moore_ball(-1.0, 2).len(), 0);
+        }
+
+        #[test]
+        fn dyn_ball_includes_boundary() {
+            let result = moore_ball(2.0, 2);
+            assert_eq!(result.len(), 12);
+            assert!(result.contains(&vec![0, 2]));
+            assert!(!result.contains(&vec![1, 2]));
+
+            assert_eq!(moore_ball(5f64.sqrt(), 2).len(), 20);
+        }
+
+        #[test]
+        fn dyn_ball_d3() {
+            assert_eq!(moore_ball(1.0, 3).len(), 6);
+            assert_eq!(moore_ball(1.5, 3).len(), 18);
+        }
+
+        #[test]
+        fn dyn_annulus_excludes_inner_block() {
+            let result = moore_annulus(1, 2, 2);
+            assert_eq!(result.len(), 16);
+            for inner in moore(1u32, 2) {
+                assert!(!result.contains(&inner));
+            }
+            assert_eq!(result, moore_shell(2, 2));
+        }
+
+        #[test]
+        fn dyn_annulus_inner_0_is_moore() {
+            assert_eq!(moore_annulus(0, 2, 3), moore(2u32, 3));
+            assert!(moore_annulus(2, 2, 3).is_empty());
+        }
+
+        #[test]
+        #[should_panic]
+        fn dyn_annulus_inner_exceeds_outer() {
+            moore_annulus(3, 2, 2);
+        }
+
+        #[test]
+        fn dyn_difference_d2_inner_1_outer_3() {
+            let inner = moore(1u32, 2);
+            let expected: Vec<Vec<isize>> = moore(3u32, 2)
+                .into_iter()
+                .filter(|offset| !inner.contains(offset))
+                .collect();
+
+            let result = moore_difference(1, 3, 2);
+            assert_eq!(result.len(), 48 - 8);
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn dyn_difference_is_set_difference() {
+            use alloc::collections::BTreeSet;
+
+            for (inner, outer, dimensions) in
+                [(0, 2, 2), (1, 2, 1), (2, 3, 3), (3, 3, 2), (3, 1, 2)]
+            {
+                let outer_set: BTreeSet<_> = moore(outer, dimensions).into_iter().collect();
+                let inner_set: BTreeSet<_> = moore(inner, dimensions).into_iter().collect();
+                let expected: BTreeSet<_> = outer_set.difference(&inner_set).cloned().collect();
+
+                let result: BTreeSet<_> = moore_difference(inner, outer, dimensions)
+                    .into_iter()
+                    .collect();
+                assert_eq!(result, expected);
+            }
+        }
+
+        #[test]
+        fn dyn_try_moore_length_overflow() {
+            assert_eq!(try_moore(1, usize::BITS), Err(MooreError::LengthOverflow));
+            assert_eq!(try_moore(u32::MAX, 4), Err(MooreError::LengthOverflow));
+            assert_eq!(try_moore(2, 3), Ok(moore(2u32, 3)));
+        }
+
+        #[test]
+        #[should_panic(expected = "the neighborhood length overflows usize")]
+        fn dyn_moore_length_overflow_panics() {
+            moore(1u32, usize::BITS);
+        }
+
+        #[test]
+        fn dyn_range_0_is_empty() {
+            for dimensions in [0, 1, 2, 3, 64, 1000] {
+                assert!(moore(0u32, dimensions).is_empty());
+                assert_eq!(moore_iter(0, dimensions).len(), 0);
+            }
+        }
+
+        #[test]
+        fn dyn_for_each_same_as_moore() {
+            for (range, dimensions) in [(0, 2), (1, 1), (1, 2), (2, 3)] {
+                let mut result = Vec::new();
+                for_each_moore(range, dimensions, |offset| result.push(offset.to_vec()));
+                assert_eq!(result, moore(range, dimensions));
+            }
+        }
+
+        #[test]
+        fn dyn_count_where_diagonal_d2_r1() {
+            let diagonal = moore_count_where(1, 2, |offset| offset.iter().all(|&c| c != 0));
+            assert_eq!(diagonal, 4);
+        }
+
+        #[test]
+        fn dyn_count_where_same_as_filtered() {
+            let predicate = |offset: &[isize]| offset.iter().sum::<isize>() > 0;
+            for (range, dimensions) in [(0, 2), (1, 1), (2, 2), (2, 3)] {
+                assert_eq!(
+                    moore_count_where(range, dimensions, predicate),
+                    moore_filtered(range, dimensions, predicate).len()
+                );
+            }
+            assert_eq!(moore_count_where(2, 3, |_| true), moore_count(2, 3));
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn dyn_hashset_membership() {
+            let neighbors = moore_hashset(2, 3);
+            assert_eq!(neighbors.len(), moore_count(2, 3));
+            assert!(neighbors.contains(&vec![-2, 0, 1]));
+            assert!(!neighbors.contains(&vec![0, 0, 0]));
+            assert!(!neighbors.contains(&vec![3, 0, 0]));
+            assert!(!neighbors.contains(&vec![1, 1]));
+
+            assert!(moore_hashset(0, 2).is_empty());
+        }
+
+        #[test]
+        fn dyn_2d_same_as_generic() {
+            use crate::generic_dimension;
+
+            for range in [0, 1, 2, 5, 100] {
+                assert_eq!(moore_2d(range), generic_dimension::moore::<isize, 2>(range));
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "simd")]
+        fn dyn_simd_same_as_generic() {
+            use crate::generic_dimension;
+
+            for range in [0, 1, 2, 3, 7] {
+                assert_eq!(moore_2d(range), generic_dimension::moore::<isize, 2>(range));
+                assert_eq!(moore_3d(range), generic_dimension::moore::<isize, 3>(range));
+            }
+        }
+
+        #[test]
+        fn dyn_same_as_reference() {
+            let result = moore(3u32, 3);
+            let expected = reference(3, 3);
+            assert_eq!(result, expected);
+        }
+
+        fn reference(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+            let size: usize = range as usize * 2 + 1;
+            let length: usize = size.pow(dimensions) - 1;
+            let mut neighbors = Vec::with_capacity(length as _);
+
+            for i in 0usize..length {
+                let mut neighbor = Vec::with_capacity(dimensions as _);
+                let mut index = if i < length / 2 { i } else { i + 1 };
+                for dimension in 1..=dimensions {
+                    let value = index % size.pow(dimension as _);
+                    neighbor.push((value / size.pow(dimension - 1)) as isize - range as isize);
+                    index -= value;
+                }
+
+                neighbors.push(neighbor);
+            }
+
+            neighbors
+        }
+
+        #[test]
+        fn dyn_builder_defaults_match_moore() {
+            assert_eq!(MooreBuilder::new(2, 3).build(), moore(2u32, 3));
+            assert_eq!(
+                MooreBuilder::new(1, 2).build_at(&[0, 0], &[3, 3]),
+                [[1, 0], [0, 1], [1, 1]]
+            );
+        }
+
+        #[test]
+        fn dyn_builder_matches_dedicated_functions() {
+            use crate::generic_dimension;
+
+            assert_eq!(
+                MooreBuilder::new(2, 2).include_center(true).build(),
+                moore_with_center(2, 2)
+            );
+            assert_eq!(
+                MooreBuilder::new(1, 3).max_nonzero(Some(2)).build(),
+                moore_connectivity(1, 3, 2)
+            );
+
+            for policy in [
+                BoundaryPolicy::Drop,
+                BoundaryPolicy::Clamp,
+                BoundaryPolicy::Wrap,
+                BoundaryPolicy::Reflect,
+                BoundaryPolicy::Saturate,
+            ] {
+                let result = MooreBuilder::new(2, 2)
+                    .boundary(policy)
+                    .build_at(&[0, 3], &[3, 5]);
+                let expected: Vec<Vec<isize>> =
+                    generic_dimension::moore_bounded([0, 3], 2, [3, 5], policy)
+                        .into_iter()
+                        .map(Vec::from)
+                        .collect();
+                assert_eq!(result, expected, "{policy:?}");
+            }
+        }
+
+        #[test]
+        fn dyn_builder_combines_options() {
+            let result = MooreBuilder::new(1, 2)
+                .include_center(true)
+                .max_nonzero(Some(1))
+                .boundary(BoundaryPolicy::Wrap)
+                .build_at(&[0, 0], &[4, 4]);
+            assert_eq!(result, [[0, 3], [3, 0], [0, 0], [1, 0], [0, 1]]);
+        }
+
+        #[test]
+        fn dyn_neighborhood_matches_moore() {
+            for (range, dimensions) in [(1, 1), (1, 2), (2, 3), (0, 2)] {
+                let neighborhood = Neighborhood::moore(range, dimensions);
+                let expected = moore(range, dimensions);
+
+                assert_eq!(neighborhood.len(), expected.len());
+                assert_eq!(neighborhood.is_empty(), expected.is_empty());
+                assert!(neighborhood.iter().eq(expected.iter().map(Vec::as_slice)));
+                for (i, offset) in expected.iter().enumerate() {
+                    assert_eq!(&neighborhood[i], offset.as_slice());
+                    assert_eq!(neighborhood.get(i), Some(offset.as_slice()));
+                }
+            }
+        }
+
+        #[test]
+        fn dyn_neighborhood_get_out_of_bounds() {
+            let neighborhood = Neighborhood::moore(1, 2);
+            assert_eq!(neighborhood.get(8), None);
+            assert_eq!(neighborhood.get(usize::MAX), None);
+            assert_eq!(Neighborhood::moore(3, 0).get(0), None);
+        }
+
+        #[test]
+        #[should_panic(expected = "index out of bounds: the len is 8 but the index is 8")]
+        fn dyn_neighborhood_index_out_of_bounds() {
+            let _ = &Neighborhood::moore(1, 2)[8];
+        }
+
+        #[test]
+        fn dyn_ring_neighbors_wraps() {
+            assert_eq!(ring_neighbors(0, 1, 5), [4, 1]);
+            assert_eq!(ring_neighbors(4, 2, 5), [2, 3, 0, 1]);
+            assert_eq!(ring_neighbors(7, 1, 5), [1, 3]);
+        }
+
+        #[test]
+        fn dyn_ring_neighbors_wraps_all_the_way_around() {
+            assert_eq!(ring_neighbors(0, 1, 2), [1]);
+            assert_eq!(ring_neighbors(2, 3, 5), [4, 0, 1, 3]);
+            assert_eq!(ring_neighbors(0, 10, 3), [2, 1]);
+            assert!(ring_neighbors(0, 4, 1).is_empty());
+        }
+
+        #[test]
+        fn dyn_ring_neighbors_huge_range() {
+            assert_eq!(ring_neighbors(1, u32::MAX, 3), [2, 0]);
+            assert_eq!(ring_neighbors(0, u32::MAX, 1 << 16).len(), (1 << 16) - 1);
+        }
+
+        #[test]
+        fn dyn_ring_neighbors_huge_ring() {
+            assert_eq!(ring_neighbors(0, 1, 1 << 30), [(1 << 30) - 1, 1]);
+            assert_eq!(ring_neighbors(5, 2, usize::MAX), [3, 4, 6, 7]);
+        }
+
+        #[test]
+        fn dyn_ring_neighbors_same_as_moore() {
+            for len in 1..8 {
+                for range in 0..10 {
+                    for center in 0..len {
+                        let mut expected = Vec::new();
+                        for offset in moore(range, 1) {
+                            let index =
+                                (center as isize + offset[0]).rem_euclid(len as isize) as usize;
+                            if index != center && !expected.contains(&index) {
+                                expected.push(index);
+                            }
+                        }
+                        assert_eq!(ring_neighbors(center, range, len), expected);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Von Neumann neighborhoods (cells within a Manhattan distance) for dynamic ranges and dynamic dimensionality.
+#[cfg(feature = "alloc")]
+pub mod von_neumann {
+    use crate::dynamic;
+    use alloc::vec::Vec;
+
+    /// Obtains the Von Neumann neighborhood for a region of width `range` for in the specified number of `dimensions`,
+    /// i.e. all offsets whose absolute coordinates sum up to at most `range`, excluding the center cell.
+    /// The offsets are returned in the same order as [`dynamic::moore`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::von_neumann::neighborhood;
+    ///
+    /// let result: Vec<Vec<isize>> = neighborhood(1, 2);
+    ///
+    /// let expected = [
+    ///              [ 0,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///              [ 0, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn neighborhood(range: u32, dimensions: u32) -> Vec<Vec<isize>> {
+        dynamic::moore_iter(range, dimensions)
+            .filter(|neighbor| {
+                neighbor.iter().map(|c| c.unsigned_abs()).sum::<usize>() <= range as usize
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn vn_d2_r1_works() {
+            let result = neighborhood(1, 2);
+            let expected = [[0, -1], [-1, 0], [1, 0], [0, 1]];
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn vn_d2_r2_works() {
+            let result = neighborhood(2, 2);
+
+            #[rustfmt::skip]
+            let expected = [
+                                   [ 0, -2],
+                         [-1, -1], [ 0, -1], [ 1, -1],
+                [-2,  0], [-1,  0],           [ 1,  0], [ 2,  0],
+                         [-1,  1], [ 0,  1], [ 1,  1],
+                                   [ 0,  2]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn vn_d3_r1_works() {
+            let result = neighborhood(1, 3);
+
+            #[rustfmt::skip]
+            let expected = [
+                [ 0, 0,-1],
+                [ 0,-1, 0], [-1, 0, 0], [ 1, 0, 0], [ 0, 1, 0],
+                [ 0, 0, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn vn_d3_r2_works() {
+            let result = neighborhood(2, 3);
+            assert_eq!(result.len(), 24);
+            assert!(result
+                .iter()
+                .all(|n| n.iter().map(|c| c.abs()).sum::<isize>() <= 2));
+
+            let moore = dynamic::moore(2u32, 3);
+            let filtered: Vec<_> = moore.into_iter().filter(|n| result.contains(n)).collect();
+            assert_eq!(result, filtered);
+        }
+    }
+}
+
+/// Moore neighborhoods for dynamic ranges and statically known dimensionality.
+#[cfg(feature = "alloc")]
+pub mod generic_dimension {
+    use crate::{Coord, Decoder};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ops::ControlFlow;
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore;
+    ///
+    /// let result: Vec<[isize; 2]> = moore(1);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    ///
+    /// Using a smaller coordinate type:
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore;
+    ///
+    /// let result = moore::<i16, 2>(1);
+    /// assert_eq!(result[0], [-1i16, -1]);
+    /// ```
+    ///
+    /// A `range` of `0` yields an empty neighborhood.
+    ///
+    /// The neighbors are returned in the same scan order as `dynamic::moore`, with the first
+    /// coordinate varying fastest. This order is guaranteed.
+    pub fn moore<T: Coord, const DIMENSIONS: usize>(range: u32) -> Vec<[T; DIMENSIONS]> {
+        assert!(DIMENSIONS < u32::MAX as _);
+        debug_assert!(T::fits(range as isize), "range exceeds the coordinate type");
+
+        if range == 0 {
+            return Vec::new();
+        }
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        let length: usize = decoder.len();
+        let mut neighbors = Vec::with_capacity(length as _);
+
+        for i in 0usize..length {
+            let neighbor: [isize; DIMENSIONS] = neighbor_at(&decoder, i);
+            neighbors.push(neighbor.map(T::from_isize));
+        }
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// into the first entries of `out`, returning the number of neighbors written. The remaining entries of `out`
+    /// are left untouched, so a single buffer sized for the largest range can be reused for smaller ones.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::{moore, moore_prealloc};
+    ///
+    /// let mut neighbors = [[0isize; 2]; 24];
+    ///
+    /// let length = moore_prealloc(1, &mut neighbors);
+    /// assert_eq!(length, 8);
+    /// assert_eq!(neighbors[..length], moore::<isize, 2>(1));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `out` is shorter than the number of neighbors.
+    pub fn moore_prealloc<const DIMENSIONS: usize>(
+        range: u32,
+        out: &mut [[isize; DIMENSIONS]],
+    ) -> usize {
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        let length = decoder.len();
+        assert!(
+            out.len() >= length,
+            "the buffer holds {} neighbors, but {length} are required",
+            out.len()
+        );
+
+        for (i, neighbor) in out[..length].iter_mut().enumerate() {
+            decoder.decode_into(i, neighbor);
+        }
+        length
+    }
+
+    /// Lazily obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`.
+    /// The neighbors are yielded in the same order as [`moore()`], and can be consumed from either end.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_iter;
+    ///
+    /// let mut neighbors = moore_iter::<2>(1);
+    /// assert_eq!(neighbors.len(), 8);
+    ///
+    /// assert_eq!(neighbors.next(), Some([-1, -1]));
+    /// assert_eq!(neighbors.next_back(), Some([1, 1]));
+    /// assert_eq!(neighbors.len(), 6);
+    /// ```
+    pub fn moore_iter<const DIMENSIONS: usize>(range: u32) -> MooreIter<DIMENSIONS> {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        MooreIter {
+            decoder,
+            front: 0,
+            back: decoder.len(),
+        }
+    }
+
+    /// An iterator over the Moore neighborhood of a region, as returned by [`moore_iter`].
+    ///
+    /// Cloning the iterator is cheap and yields an independent iterator continuing from the same position.
+    #[derive(Debug, Clone)]
+    pub struct MooreIter<const DIMENSIONS: usize> {
+        decoder: Decoder,
+        front: usize,
+        back: usize,
+    }
+
+    impl<const DIMENSIONS: usize> Iterator for MooreIter<DIMENSIONS> {
+        type Item = [isize; DIMENSIONS];
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.front >= self.back {
+                return None;
+            }
+
+            let neighbor = neighbor_at(&self.decoder, self.front);
+            self.front += 1;
+            Some(neighbor)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.back - self.front;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<const DIMENSIONS: usize> DoubleEndedIterator for MooreIter<DIMENSIONS> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.front >= self.back {
+                return None;
+            }
+
+            self.back -= 1;
+            Some(neighbor_at(&self.decoder, self.back))
+        }
+    }
+
+    impl<const DIMENSIONS: usize> ExactSizeIterator for MooreIter<DIMENSIONS> {}
+
+    /// Invokes `f` for every neighbor of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `DIMENSIONS`, in the same order as [`moore()`], without allocating.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::for_each_moore;
+    ///
+    /// let mut sum = [0; 2];
+    /// for_each_moore::<2>(1, |[x, y]| {
+    ///     sum[0] += x;
+    ///     sum[1] += y;
+    /// });
+    ///
+    /// assert_eq!(sum, [0, 0]);
+    /// ```
+    pub fn for_each_moore<const DIMENSIONS: usize>(
+        range: u32,
+        mut f: impl FnMut([isize; DIMENSIONS]),
+    ) {
+        let _ = try_for_each_moore::<DIMENSIONS, ()>(range, |offset| {
+            f(offset);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Invokes `f` for every neighbor of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `DIMENSIONS`, in the same order as [`moore()`], until `f` breaks.
+    /// No further neighbors are generated after `f` returned [`ControlFlow::Break`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    /// use moore_neighborhood::generic_dimension::try_for_each_moore;
+    ///
+    /// let result = try_for_each_moore::<2, _>(1, |offset| {
+    ///     if offset[0] > 0 {
+    ///         ControlFlow::Break(offset)
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, ControlFlow::Break([1, -1]));
+    /// ```
+    pub fn try_for_each_moore<const DIMENSIONS: usize, B>(
+        range: u32,
+        mut f: impl FnMut([isize; DIMENSIONS]) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        for i in 0usize..decoder.len() {
+            f(neighbor_at(&decoder, i))?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Describes the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// without generating it. The neighbors are generated lazily when iterating the set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_set;
+    ///
+    /// let set = moore_set::<2>(1);
+    /// assert_eq!(set.len(), 8);
+    ///
+    /// for offset in set {
+    ///     assert_ne!(offset, [0, 0]);
+    /// }
+    /// ```
+    pub fn moore_set<const DIMENSIONS: usize>(range: u32) -> MooreSet<DIMENSIONS> {
+        MooreSet { range }
+    }
+
+    /// A lazily generated Moore neighborhood, as returned by [`moore_set`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MooreSet<const DIMENSIONS: usize> {
+        range: u32,
+    }
+
+    impl<const DIMENSIONS: usize> MooreSet<DIMENSIONS> {
+        /// Gets the range of the neighborhood.
+        pub fn range(&self) -> u32 {
+            self.range
+        }
+
+        /// Gets the number of neighbors.
+        ///
+        /// ## Panics
+        ///
+        /// Panics if the number of neighbors does not fit into a `usize`, like [`Decoder::new`].
+        pub fn len(&self) -> usize {
+            Decoder::new(self.range, DIMENSIONS as _).len()
+        }
+
+        /// Determines whether the neighborhood is empty, i.e. the range is `0`.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    }
+
+    impl<const DIMENSIONS: usize> IntoIterator for MooreSet<DIMENSIONS> {
+        type Item = [isize; DIMENSIONS];
+        type IntoIter = MooreIter<DIMENSIONS>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            moore_iter(self.range)
+        }
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`,
+    /// including the center cell at its natural position in the ordering.
+    /// The returned vector has length `(2*range+1).pow(DIMENSIONS)`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_with_center;
+    ///
+    /// let result: Vec<[isize; 2]> = moore_with_center(1);
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0], [ 0, 0], [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_with_center<const DIMENSIONS: usize>(range: u32) -> Vec<[isize; DIMENSIONS]> {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        let length: usize = decoder.len() + 1;
+        let mut neighbors = Vec::with_capacity(length as _);
+
+        for index in 0usize..length {
+            let mut neighbor = [0; DIMENSIONS];
+            decoder.decode_cell_into(index, &mut neighbor);
+            neighbors.push(neighbor);
+        }
+        neighbors
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as a set, for constant-time membership tests of offsets. The set never contains the center cell.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_hashset;
+    ///
+    /// let neighbors = moore_hashset::<2>(1);
+    /// assert_eq!(neighbors.len(), 8);
+    /// assert!(neighbors.contains(&[1, -1]));
+    /// assert!(!neighbors.contains(&[0, 0]));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn moore_hashset<const DIMENSIONS: usize>(
+        range: u32,
+    ) -> std::collections::HashSet<[isize; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range).collect()
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`.
+    /// The center itself is not included.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_around;
+    ///
+    /// let result: Vec<[isize; 2]> = moore_around([0, 5], 1);
+    ///
+    /// let expected = [
+    ///     [-1, 4], [ 0, 4], [ 1, 4],
+    ///     [-1, 5],          [ 1, 5],
+    ///     [-1, 6], [ 0, 6], [ 1, 6]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_around<const DIMENSIONS: usize>(
+        center: [isize; DIMENSIONS],
+        range: u32,
+    ) -> Vec<[isize; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range)
+            .map(|mut neighbor| {
+                for (coordinate, c) in neighbor.iter_mut().zip(center) {
+                    *coordinate += c;
+                }
+                neighbor
+            })
+            .collect()
+    }
+
+    /// Obtains the absolute `i64` coordinates of the Moore neighborhood of width `range` around the specified `center`,
+    /// like [`moore_around`], but omits every neighbor with a coordinate that would overflow `i64`
+    /// instead of panicking or wrapping around.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_around_checked;
+    ///
+    /// let result = moore_around_checked([i64::MAX, 0], 1);
+    /// assert_eq!(result, [
+    ///     [i64::MAX - 1, -1], [i64::MAX, -1],
+    ///     [i64::MAX - 1,  0],
+    ///     [i64::MAX - 1,  1], [i64::MAX,  1]
+    /// ]);
+    /// ```
+    pub fn moore_around_checked<const DIMENSIONS: usize>(
+        center: [i64; DIMENSIONS],
+        range: u32,
+    ) -> Vec<[i64; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range)
+            .filter_map(|offset| {
+                let mut neighbor = [0; DIMENSIONS];
+                for (d, coordinate) in neighbor.iter_mut().enumerate() {
+                    *coordinate = center[d].checked_add(offset[d] as i64)?;
+                }
+                Some(neighbor)
+            })
+            .collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`,
+    /// with every coordinate multiplied by `step`. The number and order of neighbors is the same as for [`moore()`];
+    /// a negative `step` mirrors the neighborhood.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_scaled;
+    ///
+    /// let result: Vec<[isize; 2]> = moore_scaled(1, 2);
+    ///
+    /// let expected = [
+    ///     [-2,-2], [ 0,-2], [ 2,-2],
+    ///     [-2, 0],          [ 2, 0],
+    ///     [-2, 2], [ 0, 2], [ 2, 2]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_scaled<const DIMENSIONS: usize>(
+        range: u32,
+        step: isize,
+    ) -> Vec<[isize; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range)
+            .map(|neighbor| neighbor.map(|c| c * step))
+            .collect()
+    }
+
+    /// Obtains the neighbors with the indexes `start..end` of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `DIMENSIONS`, i.e. `moore(range)[start..end]`, without generating the
+    /// remaining neighbors. Adjacent slices concatenate to the full neighborhood.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_slice;
+    ///
+    /// // The center cell lies between index 3 and 4.
+    /// assert_eq!(moore_slice::<2>(1, 3, 5), [[-1, 0], [1, 0]]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `start > end` or if `end` exceeds the number of neighbors.
+    pub fn moore_slice<const DIMENSIONS: usize>(
+        range: u32,
+        start: usize,
+        end: usize,
+    ) -> Vec<[isize; DIMENSIONS]> {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        assert!(
+            start <= end,
+            "slice index starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= decoder.len(),
+            "range end index {end} out of range for a neighborhood of length {}",
+            decoder.len()
+        );
+
+        (start..end).map(|i| neighbor_at(&decoder, i)).collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// in consecutive chunks of `chunk` neighbors each, in the same order as [`moore()`].
+    /// The last chunk may be shorter.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_chunks;
+    ///
+    /// let sizes: Vec<usize> = moore_chunks::<2>(1, 3).map(|chunk| chunk.len()).collect();
+    /// assert_eq!(sizes, [3, 3, 2]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk` is `0`.
+    pub fn moore_chunks<const DIMENSIONS: usize>(
+        range: u32,
+        chunk: usize,
+    ) -> impl Iterator<Item = Vec<[isize; DIMENSIONS]>> {
+        assert!(chunk != 0, "chunk size must be non-zero");
+
+        let mut neighbors = moore_iter::<DIMENSIONS>(range);
+        core::iter::from_fn(move || {
+            let chunk: Vec<_> = neighbors.by_ref().take(chunk).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as `f64` offsets, in the same order as [`moore()`]. All coordinates are small integers and hence
+    /// represented exactly.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_f64;
+    ///
+    /// let result: Vec<[f64; 2]> = moore_f64(1);
+    /// assert_eq!(result[0], [-1.0, -1.0]);
+    /// assert_eq!(result[4], [1.0, 0.0]);
+    /// ```
+    pub fn moore_f64<const DIMENSIONS: usize>(range: u32) -> Vec<[f64; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range)
+            .map(|neighbor| neighbor.map(|c| c as f64))
+            .collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as `f32` offsets, in the same order as [`moore()`]. Coordinates are represented exactly for
+    /// ranges up to `2^24`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_f32;
+    ///
+    /// let result: Vec<[f32; 2]> = moore_f32(1);
+    /// assert_eq!(result[0], [-1.0, -1.0]);
+    /// assert_eq!(result[4], [1.0, 0.0]);
+    /// ```
+    pub fn moore_f32<const DIMENSIONS: usize>(range: u32) -> Vec<[f32; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range)
+            .map(|neighbor| neighbor.map(|c| c as f32))
+            .collect()
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as `i32` offsets, in the same order as [`moore()`]. This is a shorthand for `moore::<i32, DIMENSIONS>`
+    /// matching the coordinate type of most graphics APIs.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_i32;
+    ///
+    /// let result: Vec<[i32; 2]> = moore_i32(1);
+    /// assert_eq!(result[0], [-1, -1]);
+    /// assert_eq!(result[4], [1, 0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// In debug builds, panics if `range` exceeds `i32::MAX`.
+    pub fn moore_i32<const DIMENSIONS: usize>(range: u32) -> Vec<[i32; DIMENSIONS]> {
+        debug_assert!(range <= i32::MAX as u32, "range exceeds i32::MAX");
+        moore::<i32, DIMENSIONS>(range)
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as a tightly packed buffer of `i8` coordinates, e.g. for uploading to a GPU. The coordinates of the
+    /// `i`-th neighbor are stored at `buffer[i * DIMENSIONS..(i + 1) * DIMENSIONS]`, in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_i8_flat;
+    ///
+    /// let result = moore_i8_flat::<2>(1);
+    /// assert_eq!(result.len(), 16);
+    /// assert_eq!(result[..4], [-1, -1, 0, -1]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `range` exceeds `i8::MAX`.
+    pub fn moore_i8_flat<const DIMENSIONS: usize>(range: u32) -> Vec<i8> {
+        assert!(range <= i8::MAX as u32, "range exceeds i8::MAX");
+
+        let iter = moore_iter::<DIMENSIONS>(range);
+        let mut buffer = Vec::with_capacity(iter.len() * DIMENSIONS);
+        for neighbor in iter {
+            buffer.extend(neighbor.map(|c| c as i8));
+        }
+        buffer
+    }
+
+    /// Obtains the flat buffer indexes of the Moore neighborhood of width `range` around the cell at `center_index`
+    /// of a grid stored with the specified per-dimension `strides`, i.e. `center_index + dot(offset, strides)`
+    /// for every offset of [`moore()`]. No bounds checks are performed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_flat_indices;
+    ///
+    /// // A 10x10 grid stored row by row.
+    /// let result = moore_flat_indices(55, 1, [1, 10]);
+    /// assert_eq!(result, [44, 45, 46, 54, 56, 64, 65, 66]);
+    /// ```
+    pub fn moore_flat_indices<const DIMENSIONS: usize>(
+        center_index: isize,
+        range: u32,
+        strides: [isize; DIMENSIONS],
+    ) -> Vec<isize> {
+        moore_iter::<DIMENSIONS>(range)
+            .map(|offset| {
+                let delta: isize = offset
+                    .iter()
+                    .zip(strides)
+                    .map(|(c, stride)| c * stride)
+                    .sum();
+                center_index + delta
+            })
+            .collect()
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`
+    /// on a toroidal grid, i.e. each coordinate wraps around at the corresponding `bounds`.
+    ///
+    /// When the grid is small compared to the range, several offsets may wrap to the same cell.
+    /// If `dedup` is `true`, only the first occurrence of every cell is kept.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_wrapped;
+    ///
+    /// let result: Vec<[usize; 2]> = moore_wrapped([0, 1], 1, [4, 4], false);
+    ///
+    /// let expected = [
+    ///     [3, 0], [0, 0], [1, 0],
+    ///     [3, 1],         [1, 1],
+    ///     [3, 2], [0, 2], [1, 2]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_wrapped<const DIMENSIONS: usize>(
+        center: [usize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+        dedup: bool,
+    ) -> Vec<[usize; DIMENSIONS]> {
+        assert!(
+            bounds.iter().all(|&bound| bound > 0),
+            "bounds must be non-zero"
+        );
+
+        let mut neighbors: Vec<[usize; DIMENSIONS]> = moore_iter::<DIMENSIONS>(range)
+            .map(|offset| {
+                let mut neighbor = [0; DIMENSIONS];
+                for (d, coordinate) in neighbor.iter_mut().enumerate() {
+                    let value = center[d] as isize + offset[d];
+                    *coordinate = value.rem_euclid(bounds[d] as isize) as usize;
+                }
+                neighbor
+            })
+            .collect();
+
+        if dedup {
+            dedup_stable(&mut neighbors);
+        }
+        neighbors
+    }
+
+    /// Removes all but the first occurrence of every item while keeping the order of the remaining items.
+    /// Sorts the item indexes instead of comparing each item against the kept ones, and hence runs in
+    /// `O(n log n)` rather than `O(n²)`.
+    fn dedup_stable<T: Ord>(items: &mut Vec<T>) {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        // The sort is stable, so the first occurrence leads each run of equal items.
+        order.sort_by(|&a, &b| items[a].cmp(&items[b]));
+
+        let mut keep = vec![true; items.len()];
+        for pair in order.windows(2) {
+            if items[pair[0]] == items[pair[1]] {
+                keep[pair[1]] = false;
+            }
+        }
+
+        let mut keep = keep.into_iter();
+        items.retain(|_| keep.next() == Some(true));
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`
+    /// on a toroidal grid like [`moore_wrapped`], tagging each neighbor with the direction in which it
+    /// crossed the boundary along each dimension: `-1` if it wrapped below zero, `1` if it wrapped at
+    /// the upper bound and `0` otherwise. As the tags tell them apart, no neighbors are deduplicated.
+    /// A `center` outside of the grid is wrapped into it first, so the tags are relative to the wrapped center.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_wrapped_tagged;
+    ///
+    /// let result = moore_wrapped_tagged([0, 1], 1, [4, 4]);
+    ///
+    /// assert_eq!(result[0], ([3, 0], [-1, 0]));
+    /// assert_eq!(result[1], ([0, 0], [0, 0]));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_wrapped_tagged<const DIMENSIONS: usize>(
+        center: [usize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+    ) -> Vec<([usize; DIMENSIONS], [i8; DIMENSIONS])> {
+        assert!(
+            bounds.iter().all(|&bound| bound > 0),
+            "bounds must be non-zero"
+        );
+
+        let mut center = center;
+        for (coordinate, bound) in center.iter_mut().zip(bounds) {
+            *coordinate %= bound;
+        }
+
+        moore_iter::<DIMENSIONS>(range)
+            .map(|offset| {
+                let mut neighbor = [0; DIMENSIONS];
+                let mut tags = [0; DIMENSIONS];
+                for d in 0..DIMENSIONS {
+                    let value = center[d] as isize + offset[d];
+                    let bound = bounds[d] as isize;
+                    neighbor[d] = value.rem_euclid(bound) as usize;
+                    tags[d] = value.div_euclid(bound).signum() as i8;
+                }
+                (neighbor, tags)
+            })
+            .collect()
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`
+    /// on a toroidal grid like [`moore_wrapped`], but returns every reachable cell at most once and never
+    /// the center cell itself, even if an offset wraps around onto it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_wrapped_unique;
+    ///
+    /// let result: Vec<[usize; 2]> = moore_wrapped_unique([0, 0], 1, [2, 2]);
+    /// assert_eq!(result, [[1, 1], [0, 1], [1, 0]]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_wrapped_unique<const DIMENSIONS: usize>(
+        center: [usize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+    ) -> Vec<[usize; DIMENSIONS]> {
+        let mut neighbors = moore_wrapped(center, range, bounds, true);
+
+        let mut wrapped_center = center;
+        for (coordinate, bound) in wrapped_center.iter_mut().zip(bounds) {
+            *coordinate %= bound;
+        }
+        neighbors.retain(|neighbor| *neighbor != wrapped_center);
+        neighbors
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`,
+    /// omitting all cells that lie outside of the inclusive `lower..=upper` box.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_clamped;
+    ///
+    /// let result: Vec<[isize; 2]> = moore_clamped([0, 0], 1, [0, 0], [9, 9]);
+    ///
+    /// let expected = [
+    ///               [ 1, 0],
+    ///     [ 0, 1],  [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn moore_clamped<const DIMENSIONS: usize>(
+        center: [isize; DIMENSIONS],
+        range: u32,
+        lower: [isize; DIMENSIONS],
+        upper: [isize; DIMENSIONS],
+    ) -> Vec<[isize; DIMENSIONS]> {
+        let mut neighbors = moore_around(center, range);
+        neighbors.retain(|neighbor| {
+            (0..DIMENSIONS).all(|d| (lower[d]..=upper[d]).contains(&neighbor[d]))
+        });
+        neighbors
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`,
+    /// saturating each coordinate to `0..bounds` instead of omitting the cells outside of the grid.
+    ///
+    /// As saturation can collapse several offsets onto the same cell, only the first occurrence of every
+    /// cell is kept. Cells collapsing onto the `center` itself are kept as well, so an edge cell yields
+    /// its in-bounds neighbors together with the center.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_saturating;
+    ///
+    /// let result: Vec<[usize; 2]> = moore_saturating([0, 0], 1, [5, 5]);
+    /// assert_eq!(result, [[0, 0], [1, 0], [0, 1], [1, 1]]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_saturating<const DIMENSIONS: usize>(
+        center: [usize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+    ) -> Vec<[usize; DIMENSIONS]> {
+        assert!(
+            bounds.iter().all(|&bound| bound > 0),
+            "bounds must be non-zero"
+        );
+
+        let mut neighbors: Vec<[usize; DIMENSIONS]> = moore_iter::<DIMENSIONS>(range)
+            .map(|offset| {
+                let mut neighbor = [0; DIMENSIONS];
+                for (d, coordinate) in neighbor.iter_mut().enumerate() {
+                    *coordinate = center[d]
+                        .saturating_add_signed(offset[d])
+                        .min(bounds[d] - 1);
+                }
+                neighbor
+            })
+            .collect();
+
+        dedup_stable(&mut neighbors);
+        neighbors
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`,
+    /// reflecting coordinates outside of `0..bounds` back into the grid without repeating the edge
+    /// (`reflect-101`), e.g. `-1` maps to `1` and `bounds` maps to `bounds - 2`.
+    ///
+    /// Exactly one cell is returned per offset, in the same order as [`moore()`]. Cells close to the edge
+    /// may therefore be returned several times, and reflected coordinates may coincide with the `center`
+    /// itself, such as the offset `-2` for a cell at coordinate `1`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_reflected;
+    ///
+    /// let result: Vec<[usize; 2]> = moore_reflected([0, 0], 1, [4, 4]);
+    ///
+    /// let expected = [
+    ///     [1, 1], [0, 1], [1, 1],
+    ///     [1, 0],         [1, 0],
+    ///     [1, 1], [0, 1], [1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_reflected<const DIMENSIONS: usize>(
+        center: [isize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+    ) -> Vec<[usize; DIMENSIONS]> {
+        assert!(
+            bounds.iter().all(|&bound| bound > 0),
+            "bounds must be non-zero"
+        );
+
+        moore_around(center, range)
+            .into_iter()
+            .map(|neighbor| {
+                let mut cell = [0; DIMENSIONS];
+                for (d, coordinate) in cell.iter_mut().enumerate() {
+                    *coordinate = reflect_101(neighbor[d], bounds[d]);
+                }
+                cell
+            })
+            .collect()
+    }
+
+    /// Reflects `value` into `0..bound` without repeating the edge, repeatedly if required.
+    fn reflect_101(value: isize, bound: usize) -> usize {
+        let period = 2 * (bound as isize - 1);
+        if period == 0 {
+            return 0;
+        }
+
+        let value = value.rem_euclid(period);
+        if value < bound as isize {
+            value as usize
+        } else {
+            (period - value) as usize
+        }
+    }
+
+    /// The treatment of neighbors outside of the grid by [`moore_bounded`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BoundaryPolicy {
+        /// Omits the neighbors outside of the grid, like [`moore_clamped`].
+        Drop,
+        /// Clamps each coordinate into the grid, keeping one cell per offset.
+        Clamp,
+        /// Wraps each coordinate around on a toroidal grid, like [`moore_wrapped`].
+        Wrap,
+        /// Mirrors each coordinate at the edges without repeating them, like [`moore_reflected`].
+        Reflect,
+        /// Clamps each coordinate into the grid, keeping only the first occurrence of every cell,
+        /// like [`moore_saturating`].
+        Saturate,
+    }
+
+    impl BoundaryPolicy {
+        /// Treats the absolute `neighbors` outside of `0..bounds` according to this policy,
+        /// keeping the order of the remaining neighbors.
+        ///
+        /// ## Panics
+        ///
+        /// Panics if any of the `bounds` is `0`.
+        pub(crate) fn apply<C>(self, neighbors: &mut Vec<C>, bounds: &[usize])
+        where
+            C: AsRef<[isize]> + AsMut<[isize]> + Ord,
+        {
+            assert!(
+                bounds.iter().all(|&bound| bound > 0),
+                "bounds must be non-zero"
+            );
+
+            let in_bounds = |neighbor: &C| {
+                neighbor
+                    .as_ref()
+                    .iter()
+                    .zip(bounds)
+                    .all(|(&coordinate, &bound)| (0..bound as isize).contains(&coordinate))
+            };
+
+            match self {
+                BoundaryPolicy::Drop => neighbors.retain(in_bounds),
+                BoundaryPolicy::Clamp | BoundaryPolicy::Saturate => {
+                    for neighbor in neighbors.iter_mut() {
+                        for (coordinate, &bound) in neighbor.as_mut().iter_mut().zip(bounds) {
+                            *coordinate = (*coordinate).clamp(0, bound as isize - 1);
+                        }
+                    }
+
+                    if self == BoundaryPolicy::Saturate {
+                        dedup_stable(neighbors);
+                    }
+                }
+                BoundaryPolicy::Wrap => {
+                    for neighbor in neighbors.iter_mut() {
+                        for (coordinate, &bound) in neighbor.as_mut().iter_mut().zip(bounds) {
+                            *coordinate = coordinate.rem_euclid(bound as isize);
+                        }
+                    }
+                }
+                BoundaryPolicy::Reflect => {
+                    for neighbor in neighbors.iter_mut() {
+                        for (coordinate, &bound) in neighbor.as_mut().iter_mut().zip(bounds) {
+                            *coordinate = reflect_101(*coordinate, bound) as isize;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`
+    /// within a grid of the specified `bounds`, treating the neighbors outside of `0..bounds` according to `policy`.
+    /// The neighbors are returned in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::{moore_bounded, BoundaryPolicy};
+    ///
+    /// let dropped = moore_bounded([0, 0], 1, [4, 4], BoundaryPolicy::Drop);
+    /// assert_eq!(dropped, [[1, 0], [0, 1], [1, 1]]);
+    ///
+    /// let wrapped = moore_bounded([0, 0], 1, [4, 4], BoundaryPolicy::Wrap);
+    /// assert_eq!(wrapped[0], [3, 3]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any of the `bounds` is `0`.
+    pub fn moore_bounded<const DIMENSIONS: usize>(
+        center: [isize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+        policy: BoundaryPolicy,
+    ) -> Vec<[isize; DIMENSIONS]> {
+        let mut neighbors = moore_around(center, range);
+        policy.apply(&mut neighbors, &bounds);
+        neighbors
+    }
+
+    /// Lazily obtains the absolute coordinates of the Moore neighborhood of width `range` around the specified `center`,
+    /// skipping all cells outside of the grid `0..bounds`. The neighbors are yielded in the same order as [`moore()`]
+    /// and computed on demand without allocating.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::neighbors_in_bounds;
+    ///
+    /// let result: Vec<[usize; 2]> = neighbors_in_bounds([0, 0], 1, [4, 4]).collect();
+    /// assert_eq!(result, [[1, 0], [0, 1], [1, 1]]);
+    /// ```
+    pub fn neighbors_in_bounds<const DIMENSIONS: usize>(
+        center: [usize; DIMENSIONS],
+        range: u32,
+        bounds: [usize; DIMENSIONS],
+    ) -> impl Iterator<Item = [usize; DIMENSIONS]> {
+        moore_iter::<DIMENSIONS>(range).filter_map(move |offset| {
+            let mut neighbor = [0; DIMENSIONS];
+            for (d, coordinate) in neighbor.iter_mut().enumerate() {
+                *coordinate = center[d]
+                    .checked_add_signed(offset[d])
+                    .filter(|&c| c < bounds[d])?;
+            }
+            Some(neighbor)
+        })
+    }
+
+    /// Invokes `f(center, offset)` for every offset of the Moore neighborhood of width `range` and every
+    /// one of the `centers`, processing the centers in parallel. The offsets are computed once and shared
+    /// across all threads; for each center, they are visited in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::par_for_each_center;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let visits = AtomicUsize::new(0);
+    /// par_for_each_center(&[[0, 0], [5, 5], [9, 9]], 1, |_center, _offset| {
+    ///     visits.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// assert_eq!(visits.into_inner(), 3 * 8);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_for_each_center<const DIMENSIONS: usize>(
+        centers: &[[isize; DIMENSIONS]],
+        range: u32,
+        f: impl Fn([isize; DIMENSIONS], [isize; DIMENSIONS]) + Sync,
+    ) {
+        use rayon::prelude::*;
+
+        let offsets = moore::<isize, DIMENSIONS>(range);
+        centers.par_iter().for_each(|&center| {
+            for &offset in &offsets {
+                f(center, offset);
+            }
+        });
+    }
+
+    /// A precomputed Moore neighborhood with statically known dimensionality, together with its range.
+    ///
+    /// When the `serde` feature is enabled, the neighborhood can be serialized and deserialized.
+    /// Deserialization fails if the number of stored offsets does not match `(2*range+1).pow(DIMENSIONS) - 1`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::{moore, Neighborhood};
+    ///
+    /// let neighborhood = Neighborhood::<2>::new(1);
+    ///
+    /// assert_eq!(neighborhood.range(), 1);
+    /// assert_eq!(neighborhood.offsets(), moore::<isize, 2>(1));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Neighborhood<const DIMENSIONS: usize> {
+        range: u32,
+        offsets: Vec<[isize; DIMENSIONS]>,
+    }
+
+    impl<const DIMENSIONS: usize> Neighborhood<DIMENSIONS> {
+        /// Constructs the Moore neighborhood for a region of width `range`.
+        pub fn new(range: u32) -> Self {
+            Self {
+                range,
+                offsets: moore(range),
+            }
+        }
+
+        /// Gets the range the neighborhood was constructed for.
+        pub fn range(&self) -> u32 {
+            self.range
+        }
+
+        /// Gets the offsets of the neighborhood.
+        pub fn offsets(&self) -> &[[isize; DIMENSIONS]] {
+            &self.offsets
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<const DIMENSIONS: usize> serde::Serialize for Neighborhood<DIMENSIONS> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeStruct;
+
+            // serde only implements `Serialize` for arrays of up to 32 elements.
+            let offsets: Vec<&[isize]> = self.offsets.iter().map(|o| o.as_slice()).collect();
+
+            let mut state = serializer.serialize_struct("Neighborhood", 2)?;
+            state.serialize_field("range", &self.range)?;
+            state.serialize_field("offsets", &offsets)?;
+            state.end()
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de, const DIMENSIONS: usize> serde::Deserialize<'de> for Neighborhood<DIMENSIONS> {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use serde::de::Error;
+
+            #[derive(serde::Deserialize)]
+            #[serde(rename = "Neighborhood")]
+            struct RawNeighborhood {
+                range: u32,
+                offsets: Vec<Vec<isize>>,
+            }
+
+            let raw = RawNeighborhood::deserialize(deserializer)?;
+
+            let size: usize = raw.range as usize * 2 + 1;
+            let expected = size
+                .checked_pow(DIMENSIONS as _)
+                .ok_or_else(|| D::Error::custom("neighborhood length overflows usize"))?
+                - 1;
+            if raw.offsets.len() != expected {
+                return Err(D::Error::custom(format_args!(
+                    "expected {expected} offsets for range {}, got {}",
+                    raw.range,
+                    raw.offsets.len()
+                )));
+            }
+
+            let offsets = raw
+                .offsets
+                .into_iter()
+                .map(|offset| {
+                    let length = offset.len();
+                    <[isize; DIMENSIONS]>::try_from(offset)
+                        .map_err(|_| D::Error::invalid_length(length, &"an offset per dimension"))
+                })
+                .collect::<Result<_, _>>()?;
+
+            Ok(Self {
+                range: raw.range,
+                offsets,
+            })
+        }
+    }
+
+    /// Obtains the offset of the neighbor at position `index` of the Moore neighborhood of width `range`,
+    /// without generating the neighborhood. Calling this for every `index` in `0..count` reproduces [`moore()`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `index` is not smaller than the number of neighbors; see [`offset_at_checked`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::offset_at;
+    ///
+    /// assert_eq!(offset_at::<2>(0, 1), [-1, -1]);
+    /// assert_eq!(offset_at::<2>(4, 1), [ 1,  0]);
+    /// assert_eq!(offset_at::<2>(7, 1), [ 1,  1]);
+    /// ```
+    pub fn offset_at<const DIMENSIONS: usize>(index: usize, range: u32) -> [isize; DIMENSIONS] {
+        match offset_at_checked(index, range) {
+            Some(offset) => offset,
+            None => panic!("neighbor index {index} is out of range"),
+        }
+    }
+
+    /// Obtains the offset of the neighbor at position `index` of the Moore neighborhood of width `range`,
+    /// or `None` if `index` is not smaller than the number of neighbors.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::offset_at_checked;
+    ///
+    /// assert_eq!(offset_at_checked::<2>(7, 1), Some([1, 1]));
+    /// assert_eq!(offset_at_checked::<2>(8, 1), None);
+    /// ```
+    pub fn offset_at_checked<const DIMENSIONS: usize>(
+        index: usize,
+        range: u32,
+    ) -> Option<[isize; DIMENSIONS]> {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        if index >= decoder.len() {
+            return None;
+        }
+
+        Some(neighbor_at(&decoder, index))
+    }
+
+    /// Determines the position of the specified `offset` in the Moore neighborhood of width `range`,
+    /// i.e. the inverse of [`offset_at`]. Returns `None` if the offset is the center cell
+    /// or any of its coordinates lies outside of `-range..=range`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::index_of;
+    ///
+    /// assert_eq!(index_of([-1, -1], 1), Some(0));
+    /// assert_eq!(index_of([ 1,  0], 1), Some(4));
+    /// assert_eq!(index_of([ 0,  0], 1), None);
+    /// assert_eq!(index_of([ 2,  0], 1), None);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of cells of the region does not fit into a `usize`, like [`offset_at`].
+    pub fn index_of<const DIMENSIONS: usize>(
+        offset: [isize; DIMENSIONS],
+        range: u32,
+    ) -> Option<usize> {
+        assert!(DIMENSIONS < u32::MAX as _);
+        Decoder::new(range, DIMENSIONS as _).encode(&offset)
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`
+    /// as a bitset over the raster positions of the full region (see [`Decoder`]), together with the number
+    /// of bits, `(2*range+1).pow(DIMENSIONS)`. Bit `i` is stored in word `i / 64` at position `i % 64`, and is
+    /// set if the cell at raster position `i` is a neighbor, i.e. for every cell but the center.
+    /// Use [`bitset_contains`] to look up an offset.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::moore_bitset;
+    ///
+    /// let (bitset, bits) = moore_bitset::<2>(1);
+    /// assert_eq!(bits, 9);
+    /// assert_eq!(bitset, [0b1_1110_1111]);
+    /// ```
+    pub fn moore_bitset<const DIMENSIONS: usize>(range: u32) -> (Vec<u64>, usize) {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(range, DIMENSIONS as _);
+        let bits = decoder.len() + 1;
+        let mut bitset = vec![u64::MAX; bits.div_ceil(64)];
+
+        let unused = bitset.len() * 64 - bits;
+        if let Some(last) = bitset.last_mut() {
+            *last >>= unused;
+        }
+
+        let center = decoder.half_length();
+        bitset[center / 64] &= !(1 << (center % 64));
+        (bitset, bits)
+    }
+
+    /// Determines whether `offset` is part of the Moore neighborhood of width `range` represented by
+    /// a `bitset` obtained from [`moore_bitset`]. Offsets outside of the region are never contained.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::{bitset_contains, moore_bitset};
+    ///
+    /// let (bitset, _) = moore_bitset::<2>(1);
+    /// assert!(bitset_contains(&bitset, 1, [1, -1]));
+    /// assert!(!bitset_contains(&bitset, 1, [0, 0]));
+    /// assert!(!bitset_contains(&bitset, 1, [2, 0]));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of cells of the region does not fit into a `usize`, like [`moore_bitset`].
+    pub fn bitset_contains<const DIMENSIONS: usize>(
+        bitset: &[u64],
+        range: u32,
+        offset: [isize; DIMENSIONS],
+    ) -> bool {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        Decoder::new(range, DIMENSIONS as _)
+            .encode_cell(&offset)
+            .and_then(|raster| {
+                bitset
+                    .get(raster / 64)
+                    .map(|word| word & (1 << (raster % 64)))
+            })
+            .is_some_and(|bit| bit != 0)
+    }
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `DIMENSIONS`,
+    /// packing each offset into a single `u64`. Each coordinate is stored as `coordinate + range`
+    /// using `ceil(log2(2*range+1))` bits, with the first coordinate in the lowest bits.
+    /// Use [`unpack`] to restore the offsets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::{moore, moore_packed, unpack};
+    ///
+    /// let packed = moore_packed::<2>(1);
+    /// assert_eq!(packed[0], 0b00_00);
+    /// assert_eq!(packed[1], 0b00_01);
+    ///
+    /// let unpacked: Vec<[isize; 2]> = packed.iter().map(|&p| unpack(p, 1)).collect();
+    /// assert_eq!(unpacked, moore::<isize, 2>(1));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `DIMENSIONS` coordinates of the required width do not fit into 64 bits.
+    pub fn moore_packed<const DIMENSIONS: usize>(range: u32) -> Vec<u64> {
+        let bits = packed_bits::<DIMENSIONS>(range);
+        moore_iter::<DIMENSIONS>(range)
+            .map(|neighbor| {
+                neighbor.iter().rev().fold(0u64, |packed, &c| {
+                    (packed << bits) | (c + range as isize) as u64
+                })
+            })
+            .collect()
+    }
+
+    /// Restores an offset packed by [`moore_packed`] for the Moore neighborhood of width `range`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_dimension::unpack;
+    ///
+    /// assert_eq!(unpack::<2>(0b10_01, 1), [0, 1]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `DIMENSIONS` coordinates of the required width do not fit into 64 bits.
+    pub fn unpack<const DIMENSIONS: usize>(packed: u64, range: u32) -> [isize; DIMENSIONS] {
+        let bits = packed_bits::<DIMENSIONS>(range);
+        let mask = (1u64 << bits) - 1;
+
+        let mut offset = [0; DIMENSIONS];
+        for (dimension, coordinate) in offset.iter_mut().enumerate() {
+            let value = (packed >> (dimension as u32 * bits)) & mask;
+            *coordinate = value as isize - range as isize;
+        }
+        offset
+    }
+
+    /// Determines the number of bits per coordinate for packed offsets, panicking if
+    /// `DIMENSIONS` coordinates do not fit into a `u64`.
+    fn packed_bits<const DIMENSIONS: usize>(range: u32) -> u32 {
+        let size: u64 = range as u64 * 2 + 1;
+        let bits = u64::BITS - (size - 1).leading_zeros();
+        assert!(
+            (bits as usize).saturating_mul(DIMENSIONS) <= u64::BITS as usize,
+            "{DIMENSIONS} coordinates of {bits} bits do not fit into a u64"
+        );
+        bits
+    }
+
+    /// Decodes the `i`-th neighbor of the neighborhood, skipping the center cell.
+    fn neighbor_at<const DIMENSIONS: usize>(decoder: &Decoder, i: usize) -> [isize; DIMENSIONS] {
+        let mut neighbor = [0; DIMENSIONS];
+        decoder.decode_into(i, &mut neighbor);
+        neighbor
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn gen_dim_d2_r1_works() {
+            let result: Vec<[isize; 2]> = moore(1);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-1, -1], [0, -1], [1, -1],
+                [-1, 0], [1, 0],
+                [-1, 1], [0, 1], [1, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_d2_r2_works() {
+            let result: Vec<[isize; 2]> = moore(2);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-2, -2], [-1, -2], [ 0, -2], [ 1, -2], [ 2, -2],
+                [-2, -1], [-1, -1], [ 0, -1], [ 1, -1], [ 2, -1],
+                [-2,  0], [-1,  0],           [ 1,  0], [ 2,  0],
+                [-2,  1], [-1,  1], [ 0,  1], [ 1,  1], [ 2,  1],
+                [-2,  2], [-1,  2], [ 0,  2], [ 1,  2], [ 2,  2]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_prealloc_reuses_buffer() {
+            let mut buffer = [[isize::MAX; 2]; 30];
+
+            let length = moore_prealloc(2, &mut buffer);
+            assert_eq!(length, 24);
+            assert_eq!(buffer[..length], moore::<isize, 2>(2));
+            assert!(buffer[length..].iter().all(|n| *n == [isize::MAX; 2]));
+
+            let length = moore_prealloc(1, &mut buffer);
+            assert_eq!(length, 8);
+            assert_eq!(buffer[..length], moore::<isize, 2>(1));
+
+            assert_eq!(moore_prealloc(0, &mut buffer), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "the buffer holds 25 neighbors, but 26 are required")]
+        fn gen_dim_prealloc_buffer_too_short() {
+            let mut buffer = [[0isize; 3]; 25];
+            moore_prealloc(1, &mut buffer);
+        }
+
+        #[test]
+        fn gen_dim_iter_same_as_moore() {
+            assert_eq!(moore_iter::<2>(2).collect::<Vec<_>>(), moore::<isize, 2>(2));
+            assert_eq!(moore_iter::<3>(1).collect::<Vec<_>>(), moore::<isize, 3>(1));
+
+            let mut reversed = moore::<isize, 3>(2);
+            reversed.reverse();
+            assert_eq!(moore_iter::<3>(2).rev().collect::<Vec<_>>(), reversed);
+        }
+
+        #[test]
+        fn gen_dim_iter_both_ends_cover_neighborhood() {
+            let expected = moore::<isize, 2>(2);
+            let mut iter = moore_iter::<2>(2);
+
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            while let Some(neighbor) = iter.next() {
+                front.push(neighbor);
+                if let Some(neighbor) = iter.next_back() {
+                    back.push(neighbor);
+                }
+            }
+
+            assert_eq!(front.len() + back.len(), expected.len());
+            assert!(!front.contains(&[0, 0]));
+            assert!(!back.contains(&[0, 0]));
+
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front, expected);
+        }
+
+        #[test]
+        fn gen_dim_iter_clone_mid_iteration() {
+            let mut iter = moore_iter::<2>(2);
+            iter.next();
+            iter.next_back();
+
+            let copy = iter.clone();
+            assert_eq!(copy.len(), 22);
+            assert!(copy.eq(iter.by_ref()));
+            assert_eq!(iter.next(), None);
+
+            let expected = moore::<isize, 2>(2);
+            let iter = moore_iter::<2>(2).skip(5);
+            assert!(iter.clone().eq(expected[5..].iter().copied()));
+            assert!(iter.rev().eq(expected[5..].iter().rev().copied()));
+        }
+
+        #[test]
+        fn gen_dim_iter_zip_pairs_opposite_offsets() {
+            let forward = moore_iter::<3>(1);
+            let backward = moore_iter::<3>(1).rev();
+            for (a, b) in forward.zip(backward) {
+                assert_eq!(a, [-b[0], -b[1], -b[2]]);
+            }
+        }
+
+        #[test]
+        fn gen_dim_with_center_contains_origin_once() {
+            let mut result = moore_with_center::<3>(2);
+            assert_eq!(result.len(), 125);
+
+            let position = result.iter().position(|n| *n == [0; 3]).unwrap();
+            assert_eq!(result.iter().filter(|n| **n == [0; 3]).count(), 1);
+            assert_eq!(position, 62);
+
+            result.remove(position);
+            assert_eq!(result, moore::<isize, 3>(2));
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn gen_dim_hashset_membership() {
+            let neighbors = moore_hashset::<2>(3);
+            assert_eq!(neighbors.len(), 48);
+            assert!(neighbors.contains(&[3, -3]));
+            assert!(!neighbors.contains(&[0, 0]));
+            assert!(!neighbors.contains(&[4, 0]));
+        }
+
+        #[test]
+        fn gen_dim_coord_types_match_isize() {
+            let expected = moore::<isize, 2>(2);
+
+            let result = moore::<i32, 2>(2);
+            let cast: Vec<[i32; 2]> = expected.iter().map(|n| n.map(|c| c as i32)).collect();
+            assert_eq!(result, cast);
+
+            let result = moore::<i8, 2>(2);
+            let cast: Vec<[i8; 2]> = expected.iter().map(|n| n.map(|c| c as i8)).collect();
+            assert_eq!(result, cast);
+        }
+
+        #[test]
+        #[cfg(feature = "num-traits")]
+        fn gen_dim_coord_num_traits_i128() {
+            let expected = moore::<isize, 3>(1);
+            let result = moore::<i128, 3>(1);
+            let cast: Vec<[i128; 3]> = expected.iter().map(|n| n.map(|c| c as i128)).collect();
+            assert_eq!(result, cast);
+        }
+
+        #[test]
+        fn gen_dim_around_d2_r1_works() {
+            let result = moore_around([5, 5], 1);
+
+            #[rustfmt::skip]
+            let expected = [
+                [4, 4], [5, 4], [6, 4],
+                [4, 5],         [6, 5],
+                [4, 6], [5, 6], [6, 6]
+            ];
+
+            assert_eq!(result, expected);
+            assert!(!result.contains(&[5, 5]));
+        }
+
+        #[test]
+        fn gen_dim_around_checked_drops_overflow() {
+            let result = moore_around_checked([i64::MAX, i64::MAX], 1);
+            assert_eq!(
+                result,
+                [
+                    [i64::MAX - 1, i64::MAX - 1],
+                    [i64::MAX, i64::MAX - 1],
+                    [i64::MAX - 1, i64::MAX]
+                ]
+            );
+
+            let result = moore_around_checked([i64::MIN + 1, 0, 0], 2);
+            assert_eq!(result.len(), 124 - 25);
+            assert_eq!(result.iter().map(|n| n[0]).min(), Some(i64::MIN));
+        }
+
+        #[test]
+        fn gen_dim_around_checked_same_as_around() {
+            let expected: Vec<[i64; 3]> = moore_around([3, -7, 11], 2)
+                .into_iter()
+                .map(|n| n.map(|c| c as i64))
+                .collect();
+            assert_eq!(moore_around_checked([3, -7, 11], 2), expected);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_corner_wraps_to_opposite_edges() {
+            let result = moore_wrapped([0, 0], 1, [4, 4], false);
+
+            #[rustfmt::skip]
+            let expected = [
+                [3, 3], [0, 3], [1, 3],
+                [3, 0],         [1, 0],
+                [3, 1], [0, 1], [1, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_dedup() {
+            let result = moore_wrapped([0, 0], 1, [2, 2], false);
+            assert_eq!(result.len(), 8);
+
+            let result = moore_wrapped([0, 0], 1, [2, 2], true);
+            assert_eq!(result, [[1, 1], [0, 1], [1, 0]]);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_dedup_large_range() {
+            let result = moore_wrapped([1, 1], 50, [3, 3], true);
+
+            #[rustfmt::skip]
+            let expected = [
+                [2, 2], [0, 2], [1, 2],
+                [2, 0], [0, 0], [1, 0],
+                [2, 1], [0, 1], [1, 1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_dedup_stable_keeps_first_occurrence() {
+            let mut items = vec![3, 1, 3, 2, 1, 0, 2];
+            dedup_stable(&mut items);
+            assert_eq!(items, [3, 1, 2, 0]);
+        }
+
+        #[test]
+        fn gen_dim_flat_indices_10x10() {
+            let result = moore_flat_indices(55, 1, [1, 10]);
+            assert_eq!(result, [44, 45, 46, 54, 56, 64, 65, 66]);
+        }
+
+        #[test]
+        fn gen_dim_flat_indices_same_as_coordinates() {
+            let strides = [1, 7, 49];
+            let result = moore_flat_indices(171, 2, strides);
+            let expected: Vec<isize> = moore_around([3, 3, 3], 2)
+                .into_iter()
+                .map(|cell| cell.iter().zip(strides).map(|(c, s)| c * s).sum())
+                .collect();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_tagged_corner() {
+            let result = moore_wrapped_tagged([0, 4], 1, [5, 5]);
+            let cells: Vec<[usize; 2]> = result.iter().map(|(cell, _)| *cell).collect();
+            assert_eq!(cells, moore_wrapped([0, 4], 1, [5, 5], false));
+
+            #[rustfmt::skip]
+            let expected = [
+                [-1, 0], [0, 0], [0, 0],
+                [-1, 0],         [0, 0],
+                [-1, 1], [0, 1], [0, 1]
+            ];
+
+            let tags: Vec<[i8; 2]> = result.iter().map(|(_, tags)| *tags).collect();
+            assert_eq!(tags, expected);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_tagged_interior() {
+            let result = moore_wrapped_tagged([2, 2, 2], 1, [5, 5, 5]);
+            assert_eq!(result.len(), 26);
+            assert!(result.iter().all(|(_, tags)| *tags == [0; 3]));
+        }
+
+        #[test]
+        fn gen_dim_wrapped_tagged_center_out_of_bounds() {
+            let result = moore_wrapped_tagged([7, 2], 1, [5, 5]);
+            assert_eq!(result, moore_wrapped_tagged([2, 2], 1, [5, 5]));
+            assert!(result.iter().all(|(_, tags)| *tags == [0; 2]));
+
+            let result = moore_wrapped_tagged([5, 0], 1, [5, 5]);
+            assert_eq!(result[0], ([4, 4], [-1, -1]));
+            assert_eq!(result[7], ([1, 1], [0, 0]));
+        }
+
+        #[test]
+        #[should_panic(expected = "bounds must be non-zero")]
+        fn gen_dim_wrapped_tagged_zero_bound() {
+            moore_wrapped_tagged([0, 0], 1, [5, 0]);
+        }
+
+        #[test]
+        #[should_panic(expected = "bounds must be non-zero")]
+        fn gen_dim_wrapped_zero_bound() {
+            moore_wrapped([0, 0], 1, [0, 5], false);
+        }
+
+        #[test]
+        fn gen_dim_wrapped_unique_small_torus() {
+            let result = moore_wrapped_unique([1, 1], 2, [3, 3]);
+            assert_eq!(result.len(), 8);
+            assert!(!result.contains(&[1, 1]));
+            for neighbor in moore_wrapped([1, 1], 1, [3, 3], false) {
+                assert_eq!(result.iter().filter(|n| **n == neighbor).count(), 1);
+            }
+        }
+
+        #[test]
+        fn gen_dim_wrapped_unique_tiny_torus() {
+            assert_eq!(moore_wrapped_unique([0], 3, [2]), [[1]]);
+            assert!(moore_wrapped_unique([0, 0], 2, [1, 1]).is_empty());
+        }
+
+        #[test]
+        fn gen_dim_wrapped_unique_large_grid() {
+            assert_eq!(
+                moore_wrapped_unique([5, 5], 1, [10, 10]),
+                moore_wrapped([5, 5], 1, [10, 10], false)
+            );
+        }
+
+        #[test]
+        fn gen_dim_clamped_corner() {
+            let result = moore_clamped([0, 0], 1, [0, 0], [4, 4]);
+            assert_eq!(result, [[1, 0], [0, 1], [1, 1]]);
+
+            let result = moore_clamped([4, 4], 1, [0, 0], [4, 4]);
+            assert_eq!(result, [[3, 3], [4, 3], [3, 4]]);
+        }
+
+        #[test]
+        fn gen_dim_clamped_edge() {
+            let result = moore_clamped([0, 2], 1, [0, 0], [4, 4]);
+            assert_eq!(result, [[0, 1], [1, 1], [1, 2], [0, 3], [1, 3]]);
+        }
+
+        #[test]
+        fn gen_dim_clamped_interior() {
+            let result = moore_clamped([2, 2], 1, [0, 0], [4, 4]);
+            assert_eq!(result, moore_around([2, 2], 1));
+        }
+
+        #[test]
+        fn gen_dim_saturating_corner() {
+            let result = moore_saturating([0, 0], 1, [5, 5]);
+            assert_eq!(result, [[0, 0], [1, 0], [0, 1], [1, 1]]);
+
+            let result = moore_saturating([0, 0], 2, [5, 5]);
+            assert_eq!(result.len(), 9);
+            assert_eq!(result[0], [0, 0]);
+            assert_eq!(result.iter().filter(|&&cell| cell == [0, 0]).count(), 1);
+        }
+
+        #[test]
+        fn gen_dim_saturating_upper_corner() {
+            let result = moore_saturating([4, 4], 1, [5, 5]);
+            assert_eq!(result, [[3, 3], [4, 3], [3, 4], [4, 4]]);
+        }
+
+        #[test]
+        fn gen_dim_saturating_interior() {
+            let result = moore_saturating([2, 2], 1, [5, 5]);
+            let expected: Vec<[usize; 2]> = moore_around([2, 2], 1)
+                .into_iter()
+                .map(|n| n.map(|c| c as usize))
+                .collect();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_saturating_large_range() {
+            let result = moore_saturating([1, 0], 40, [3, 2]);
+            assert_eq!(result, [[0, 0], [1, 0], [2, 0], [0, 1], [1, 1], [2, 1]]);
+        }
+
+        #[test]
+        fn gen_dim_reflected_near_edge() {
+            let result = moore_reflected([1, 1], 2, [5, 5]);
+            assert_eq!(result.len(), 24);
+
+            // The offset -2 reflects onto the center's own coordinate.
+            assert_eq!(result[0], [1, 1]);
+            assert!(result.iter().all(|cell| cell.iter().all(|&c| c < 5)));
+
+            let column: Vec<usize> = result[..5].iter().map(|cell| cell[0]).collect();
+            assert_eq!(column, [1, 0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn gen_dim_reflected_upper_edge() {
+            let result = moore_reflected([3, 2], 1, [4, 4]);
+            assert_eq!(
+                result,
+                [
+                    [2, 1],
+                    [3, 1],
+                    [2, 1],
+                    [2, 2],
+                    [2, 2],
+                    [2, 3],
+                    [3, 3],
+                    [2, 3]
+                ]
+            );
+        }
+
+        #[test]
+        fn gen_dim_reflected_interior() {
+            let result = moore_reflected([2, 2], 1, [5, 5]);
+            let expected: Vec<[usize; 2]> = moore_around([2, 2], 1)
+                .into_iter()
+                .map(|n| n.map(|c| c as usize))
+                .collect();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_reflect_101() {
+            let reflected: Vec<usize> = (-6..=9).map(|v| reflect_101(v, 4)).collect();
+            assert_eq!(reflected, [0, 1, 2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0, 1, 2, 3]);
+            assert_eq!(reflect_101(-3, 1), 0);
+        }
+
+        #[test]
+        fn gen_dim_bounded_corner_cell() {
+            let bounded = |policy| moore_bounded([0, 0], 1, [4, 4], policy);
+
+            assert_eq!(bounded(BoundaryPolicy::Drop), [[1, 0], [0, 1], [1, 1]]);
+            #[rustfmt::skip]
+            assert_eq!(bounded(BoundaryPolicy::Clamp), [
+                [0, 0], [0, 0], [1, 0],
+                [0, 0],         [1, 0],
+                [0, 1], [0, 1], [1, 1]
+            ]);
+            #[rustfmt::skip]
+            assert_eq!(bounded(BoundaryPolicy::Wrap), [
+                [3, 3], [0, 3], [1, 3],
+                [3, 0],         [1, 0],
+                [3, 1], [0, 1], [1, 1]
+            ]);
+            #[rustfmt::skip]
+            assert_eq!(bounded(BoundaryPolicy::Reflect), [
+                [1, 1], [0, 1], [1, 1],
+                [1, 0],         [1, 0],
+                [1, 1], [0, 1], [1, 1]
+            ]);
+            assert_eq!(
+                bounded(BoundaryPolicy::Saturate),
+                [[0, 0], [1, 0], [0, 1], [1, 1]]
+            );
+        }
+
+        #[test]
+        fn gen_dim_bounded_same_as_dedicated_functions() {
+            let to_usize = |neighbors: Vec<[isize; 2]>| -> Vec<[usize; 2]> {
+                neighbors
+                    .into_iter()
+                    .map(|n| n.map(|c| c as usize))
+                    .collect()
+            };
+
+            for center in [[0, 0], [1, 2], [4, 0], [2, 2]] {
+                let unsigned = center.map(|c| c as usize);
+                let bounds = [5, 3];
+
+                assert_eq!(
+                    moore_bounded(center, 2, bounds, BoundaryPolicy::Drop),
+                    moore_clamped(center, 2, [0, 0], [4, 2])
+                );
+                assert_eq!(
+                    to_usize(moore_bounded(center, 2, bounds, BoundaryPolicy::Wrap)),
+                    moore_wrapped(unsigned, 2, bounds, false)
+                );
+                assert_eq!(
+                    to_usize(moore_bounded(center, 2, bounds, BoundaryPolicy::Reflect)),
+                    moore_reflected(center, 2, bounds)
+                );
+                assert_eq!(
+                    to_usize(moore_bounded(center, 2, bounds, BoundaryPolicy::Saturate)),
+                    moore_saturating(unsigned, 2, bounds)
+                );
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn gen_dim_par_for_each_center_same_as_serial() {
+            use core::sync::atomic::{AtomicIsize, Ordering};
+
+            const WIDTH: isize = 12;
+            let centers: Vec<[isize; 2]> =
+                (0..WIDTH * WIDTH).map(|i| [i % WIDTH, i / WIDTH]).collect();
+            let index =
+                |[x, y]: [isize; 2]| (y.rem_euclid(WIDTH) * WIDTH + x.rem_euclid(WIDTH)) as usize;
+
+            // Every cell accumulates the weighted offsets it is reached by from its neighbors.
+            let parallel: Vec<AtomicIsize> =
+                (0..WIDTH * WIDTH).map(|_| AtomicIsize::new(0)).collect();
+            par_for_each_center(&centers, 2, |center, offset| {
+                let neighbor = [center[0] + offset[0], center[1] + offset[1]];
+                let weight = center[0] * 3 + center[1] + offset[0] * 7 - offset[1];
+                parallel[index(neighbor)].fetch_add(weight, Ordering::Relaxed);
+            });
+
+            let mut serial = vec![0; (WIDTH * WIDTH) as usize];
+            for &center in &centers {
+                for offset in moore::<isize, 2>(2) {
+                    let neighbor = [center[0] + offset[0], center[1] + offset[1]];
+                    serial[index(neighbor)] +=
+                        center[0] * 3 + center[1] + offset[0] * 7 - offset[1];
+                }
+            }
+
+            let parallel: Vec<isize> = parallel.into_iter().map(AtomicIsize::into_inner).collect();
+            assert_eq!(parallel, serial);
+        }
+
+        #[test]
+        fn gen_dim_in_bounds_interior() {
+            let result: Vec<[usize; 2]> = neighbors_in_bounds([2, 2], 1, [5, 5]).collect();
+            let expected: Vec<[usize; 2]> = moore_around([2, 2], 1)
+                .into_iter()
+                .map(|n| n.map(|c| c as usize))
+                .collect();
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn gen_dim_in_bounds_edge() {
+            let result: Vec<[usize; 2]> = neighbors_in_bounds([2, 4], 1, [5, 5]).collect();
+            assert_eq!(result, [[1, 3], [2, 3], [3, 3], [1, 4], [3, 4]]);
+        }
+
+        #[test]
+        fn gen_dim_in_bounds_corner() {
+            let result: Vec<[usize; 3]> = neighbors_in_bounds([4, 4, 4], 1, [5, 5, 5]).collect();
+            assert_eq!(result.len(), 7);
+            assert!(result.iter().all(|n| n.iter().all(|&c| c == 3 || c == 4)));
+
+            // A grid of a single cell has no neighbors at all.
+            assert_eq!(neighbors_in_bounds([0, 0], 2, [1, 1]).count(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "bounds must be non-zero")]
+        fn gen_dim_bounded_zero_bound() {
+            moore_bounded([0, 0], 1, [0, 4], BoundaryPolicy::Drop);
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn gen_dim_neighborhood_serde_round_trip() {
+            let neighborhood = Neighborhood::<3>::new(2);
+            let json = serde_json::to_string(&neighborhood).unwrap();
+            let result: Neighborhood<3> = serde_json::from_str(&json).unwrap();
+            assert_eq!(result, neighborhood);
+            assert_eq!(result.offsets(), moore::<isize, 3>(2));
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn gen_dim_neighborhood_serde_validates_length() {
+            let json = r#"{"range":1,"offsets":[[-1,-1],[1,1]]}"#;
+            assert!(serde_json::from_str::<Neighborhood<2>>(json).is_err());
+
+            let json = r#"{"range":1,"offsets":[[-1],[1]]}"#;
+            assert!(serde_json::from_str::<Neighborhood<2>>(json).is_err());
+            assert!(serde_json::from_str::<Neighborhood<1>>(json).is_ok());
+        }
+
+        #[test]
+        fn gen_dim_offset_at_same_as_moore() {
+            let expected = moore::<isize, 3>(2);
+            for (index, neighbor) in expected.iter().enumerate() {
+                assert_eq!(offset_at::<3>(index, 2), *neighbor);
+            }
+            assert_eq!(offset_at_checked::<3>(expected.len(), 2), None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn gen_dim_offset_at_out_of_range() {
+            offset_at::<2>(8, 1);
+        }
+
+        #[test]
+        fn gen_dim_index_of_round_trip() {
+            let neighbors = moore::<isize, 2>(2);
+            for (index, neighbor) in neighbors.iter().enumerate() {
+                assert_eq!(index_of(*neighbor, 2), Some(index));
+                assert_eq!(
+                    offset_at::<2>(index_of(*neighbor, 2).unwrap(), 2),
+                    *neighbor
+                );
+            }
+        }
+
+        #[test]
+        fn gen_dim_index_of_invalid_offsets() {
+            assert_eq!(index_of([0, 0], 2), None);
+            assert_eq!(index_of([3, 0], 2), None);
+            assert_eq!(index_of([0, -3], 2), None);
+            assert_eq!(index_of([0, 0, 0], 0), None);
+        }
+
+        #[test]
+        fn gen_dim_index_of_large_range() {
+            let range = 1 << 20;
+            let last = Decoder::new(range, 3).len() - 1;
+            assert_eq!(index_of([range as isize; 3], range), Some(last));
+            assert_eq!(offset_at::<3>(last, range), [range as isize; 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "the neighborhood length overflows usize")]
+        fn gen_dim_index_of_overflow() {
+            index_of::<2>([0, 0], u32::MAX);
+        }
+
+        #[test]
+        fn gen_dim_scaled_steps() {
+            assert_eq!(moore_scaled::<3>(2, 1), moore::<isize, 3>(2));
+
+            let result = moore_scaled::<2>(1, 3);
+            assert_eq!(result.len(), 8);
+            assert!(result.iter().flatten().all(|c| [-3, 0, 3].contains(c)));
+        }
+
+        #[test]
+        fn gen_dim_scaled_negative_step_mirrors() {
+            let mut mirrored = moore_scaled::<2>(2, 2);
+            mirrored.reverse();
+            assert_eq!(moore_scaled::<2>(2, -2), mirrored);
+        }
+
+        #[test]
+        fn gen_dim_range_0_is_empty() {
+            assert!(moore::<isize, 1>(0).is_empty());
+            assert!(moore::<isize, 2>(0).is_empty());
+            assert!(moore::<i8, 5>(0).is_empty());
+            assert_eq!(moore_iter::<3>(0).len(), 0);
+        }
+
+        #[test]
+        fn gen_dim_set_same_as_moore() {
+            let set = moore_set::<3>(2);
+            assert_eq!(set.len(), 124);
+            assert!(!set.is_empty());
+
+            let mut result = Vec::new();
+            for offset in set {
+                result.push(offset);
+            }
+            assert_eq!(result, moore::<isize, 3>(2));
+        }
+
+        #[test]
+        fn gen_dim_set_range_0_is_empty() {
+            let set = moore_set::<3>(0);
+            assert!(set.is_empty());
+            assert_eq!(set.into_iter().count(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "the neighborhood length overflows usize")]
+        fn gen_dim_set_len_overflow() {
+            let _ = moore_set::<64>(1).len();
+        }
+
+        #[test]
+        fn gen_dim_for_each_same_as_moore() {
+            let mut result = Vec::new();
+            for_each_moore::<3>(2, |offset| result.push(offset));
+            assert_eq!(result, moore::<isize, 3>(2));
+        }
+
+        #[test]
+        fn gen_dim_try_for_each_breaks_early() {
+            let mut seen = Vec::new();
+            let result = try_for_each_moore::<3, _>(1, |offset| {
+                seen.push(offset);
+                if seen.len() == 3 {
+                    ControlFlow::Break(offset)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+
+            assert_eq!(result, ControlFlow::Break([1, -1, -1]));
+            assert_eq!(seen, moore::<isize, 3>(1)[..3]);
+        }
+
+        #[test]
+        fn gen_dim_try_for_each_continues() {
+            let mut count = 0;
+            let result = try_for_each_moore::<2, ()>(2, |_| {
+                count += 1;
+                ControlFlow::Continue(())
+            });
+            assert_eq!(result, ControlFlow::Continue(()));
+            assert_eq!(count, 24);
+        }
+
+        #[test]
+        fn gen_dim_bitset_only_center_unset() {
+            for range in 0..=4 {
+                let (bitset, bits) = moore_bitset::<3>(range);
+                let size = range as usize * 2 + 1;
+                assert_eq!(bits, size.pow(3));
+                assert_eq!(bitset.len(), bits.div_ceil(64));
+
+                let set: u32 = bitset.iter().map(|word| word.count_ones()).sum();
+                assert_eq!(set as usize, bits - 1);
+
+                let center = bits / 2;
+                assert_eq!(bitset[center / 64] & (1 << (center % 64)), 0);
+            }
+        }
+
+        #[test]
+        fn gen_dim_bitset_contains() {
+            let (bitset, _) = moore_bitset::<2>(3);
+            for offset in moore_with_center::<2>(4) {
+                let expected = offset != [0, 0] && offset.iter().all(|c| c.abs() <= 3);
+                assert_eq!(bitset_contains(&bitset, 3, offset), expected);
+            }
+        }
+
+        #[test]
+        fn gen_dim_bitset_contains_large_range() {
+            let range = 1 << 20;
+            assert!(!bitset_contains(&[u64::MAX], range, [range as isize; 3]));
+            assert!(bitset_contains(&[u64::MAX], range, [-(range as isize); 3]));
+        }
+
+        #[test]
+        fn gen_dim_packed_round_trip() {
+            for range in 1..=3 {
+                let packed = moore_packed::<2>(range);
+                let unpacked: Vec<[isize; 2]> = packed.iter().map(|&p| unpack(p, range)).collect();
+                assert_eq!(unpacked, moore::<isize, 2>(range));
+
+                let packed = moore_packed::<3>(range);
+                let unpacked: Vec<[isize; 3]> = packed.iter().map(|&p| unpack(p, range)).collect();
+                assert_eq!(unpacked, moore::<isize, 3>(range));
+            }
+        }
+
+        #[test]
+        fn gen_dim_packed_bit_width() {
+            // Range 2 requires 3 bits for the five coordinate values 0..=4.
+            let packed = moore_packed::<2>(2);
+            assert_eq!(packed[0], 0);
+            assert_eq!(*packed.last().unwrap(), 0b100_100);
+            assert_eq!(unpack::<32>(u64::MAX, 1), [2; 32]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn gen_dim_packed_overflow_panics() {
+            moore_packed::<33>(1);
+        }
+
+        #[test]
+        fn gen_dim_float_same_as_moore() {
+            for range in 0..=3 {
+                let expected: Vec<[f64; 3]> = moore::<isize, 3>(range)
+                    .into_iter()
+                    .map(|n| n.map(|c| c as f64))
+                    .collect();
+                assert_eq!(moore_f64::<3>(range), expected);
+
+                let expected: Vec<[f32; 2]> = moore::<isize, 2>(range)
+                    .into_iter()
+                    .map(|n| n.map(|c| c as f32))
+                    .collect();
+                assert_eq!(moore_f32::<2>(range), expected);
+            }
+        }
+
+        #[test]
+        fn gen_dim_i32_same_as_moore() {
+            for range in 0..=3 {
+                let expected: Vec<[i32; 3]> = moore::<isize, 3>(range)
+                    .into_iter()
+                    .map(|n| n.map(|c| c as i32))
+                    .collect();
+                assert_eq!(moore_i32::<3>(range), expected);
+            }
+        }
+
+        #[test]
+        fn gen_dim_i8_flat_reconstructs_moore() {
+            let buffer = moore_i8_flat::<2>(2);
+            assert_eq!(buffer.len(), 24 * 2);
+
+            let neighbors: Vec<[isize; 2]> = buffer
+                .chunks_exact(2)
+                .map(|c| [c[0] as isize, c[1] as isize])
+                .collect();
+            assert_eq!(neighbors, moore::<isize, 2>(2));
+
+            let buffer = moore_i8_flat::<2>(127);
+            assert_eq!(buffer.len(), moore_iter::<2>(127).len() * 2);
+            assert_eq!(buffer[..2], [-127, -127]);
+        }
+
+        #[test]
+        #[should_panic(expected = "range exceeds i8::MAX")]
+        fn gen_dim_i8_flat_range_too_large() {
+            moore_i8_flat::<1>(128);
+        }
+
+        #[test]
+        fn gen_dim_float_is_exact() {
+            for neighbor in moore_f64::<2>(5) {
+                assert!(neighbor.iter().all(|c| c.fract() == 0.0));
+            }
+        }
+
+        #[test]
+        fn gen_dim_chunks_partial_last_chunk() {
+            let chunks: Vec<Vec<[isize; 3]>> = moore_chunks::<3>(1, 4).collect();
+            assert_eq!(chunks.len(), 7);
+            assert!(chunks[..6].iter().all(|chunk| chunk.len() == 4));
+            assert_eq!(chunks[6], [[0, 1, 1], [1, 1, 1]]);
+
+            let concatenated: Vec<[isize; 3]> = chunks.into_iter().flatten().collect();
+            assert_eq!(concatenated, moore::<isize, 3>(1));
+        }
+
+        #[test]
+        fn gen_dim_chunks_larger_than_neighborhood() {
+            let chunks: Vec<_> = moore_chunks::<2>(1, 100).collect();
+            assert_eq!(chunks, [moore::<isize, 2>(1)]);
+            assert_eq!(moore_chunks::<2>(0, 4).count(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn gen_dim_chunks_zero_panics() {
+            let _ = moore_chunks::<2>(1, 0);
+        }
+
+        #[test]
+        fn gen_dim_slice_straddles_center() {
+            let neighbors = moore::<isize, 3>(2);
+            let half_length = neighbors.len() / 2;
+
+            for (start, end) in [
+                (half_length - 1, half_length + 1),
+                (0, half_length + 3),
+                (half_length, 124),
+            ] {
+                assert_eq!(moore_slice::<3>(2, start, end), neighbors[start..end]);
+            }
+        }
+
+        #[test]
+        fn gen_dim_slices_concatenate() {
+            let mut concatenated = Vec::new();
+            for start in (0..24).step_by(5) {
+                concatenated.extend(moore_slice::<2>(2, start, (start + 5).min(24)));
+            }
+            assert_eq!(concatenated, moore::<isize, 2>(2));
+            assert!(moore_slice::<2>(2, 7, 7).is_empty());
+        }
+
+        #[test]
+        #[should_panic]
+        fn gen_dim_slice_out_of_range_panics() {
+            moore_slice::<2>(1, 4, 9);
+        }
+
+        #[test]
+        fn gen_dim_d3_r1_works() {
+            let result = moore::<isize, 3>(1);
+
+            #[rustfmt::skip]
+            let expected = [
+                [-1, -1, -1], [ 0, -1, -1], [ 1, -1, -1],
+                [-1,  0, -1], [ 0,  0, -1], [ 1,  0, -1],
+                [-1,  1, -1], [ 0,  1, -1], [ 1,  1, -1],
+
+                [-1, -1,  0], [ 0, -1,  0], [ 1, -1,  0],
+                [-1,  0,  0],               [ 1,  0,  0],
+                [-1,  1,  0], [ 0,  1,  0], [ 1,  1,  0],
+
+                [-1, -1,  1], [ 0, -1,  1], [ 1, -1,  1],
+                [-1,  0,  1], [ 0,  0,  1], [ 1,  0,  1],
+                [-1,  1,  1], [ 0,  1,  1], [ 1,  1,  1]
+            ];
+
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+/// Distances of offsets from the center cell, for classifying offsets without generating a neighborhood.
+pub mod distance {
+    /// Determines the Chebyshev distance of the specified `offset` from the center cell,
+    /// i.e. the maximum of its absolute coordinates. This is the smallest range of a
+    /// Moore neighborhood containing the offset.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::distance::chebyshev;
+    ///
+    /// assert_eq!(chebyshev(&[-3, 1, 2]), 3);
+    /// assert_eq!(chebyshev(&[0, 0]), 0);
+    /// ```
+    pub fn chebyshev(offset: &[isize]) -> usize {
+        offset.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0)
+    }
+
+    /// Determines the Manhattan distance of the specified `offset` from the center cell,
+    /// i.e. the sum of its absolute coordinates. This is the smallest range of a
+    /// Von Neumann neighborhood containing the offset.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::distance::manhattan;
+    ///
+    /// assert_eq!(manhattan(&[-3, 1, 2]), 6);
+    /// assert_eq!(manhattan(&[0, 0]), 0);
+    /// ```
+    pub fn manhattan(offset: &[isize]) -> usize {
+        offset.iter().map(|c| c.unsigned_abs()).sum()
+    }
+
+    /// Determines whether the specified `offset` is part of the Moore neighborhood of width `range`.
+    /// As the neighborhood does not contain the center cell, this is `false` for the origin.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::distance::is_in_moore;
+    ///
+    /// assert!(is_in_moore(&[-1, 1], 1));
+    /// assert!(!is_in_moore(&[2, 0], 1));
+    /// assert!(!is_in_moore(&[0, 0], 1));
+    /// ```
+    pub fn is_in_moore(offset: &[isize], range: u32) -> bool {
+        let distance = chebyshev(offset);
+        distance > 0 && distance <= range as usize
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn distance_origin_is_zero() {
+            assert_eq!(chebyshev(&[0, 0, 0]), 0);
+            assert_eq!(manhattan(&[0, 0, 0]), 0);
+            assert_eq!(chebyshev(&[]), 0);
+            assert_eq!(manhattan(&[]), 0);
+            assert!(!is_in_moore(&[0, 0, 0], 3));
+        }
+
+        #[test]
+        fn distance_mixed_signs() {
+            assert_eq!(chebyshev(&[2, -5, 3]), 5);
+            assert_eq!(manhattan(&[2, -5, 3]), 10);
+            assert_eq!(chebyshev(&[-1, 1]), 1);
+            assert_eq!(manhattan(&[-1, 1]), 2);
+        }
+
+        #[test]
+        fn distance_is_in_moore() {
+            assert!(is_in_moore(&[2, -5, 3], 5));
+            assert!(!is_in_moore(&[2, -5, 3], 4));
+            assert!(!is_in_moore(&[1, 0], 0));
+            assert!(is_in_moore(&[-4, 0, 1], u32::MAX));
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn distance_agrees_with_moore() {
+            use crate::dynamic;
+
+            let neighborhood = dynamic::moore(2u32, 2);
+            for offset in dynamic::moore_with_center(3, 2) {
+                assert_eq!(is_in_moore(&offset, 2), neighborhood.contains(&offset));
+            }
+        }
+    }
+}
+
+/// Move offsets of chess pieces on a two-dimensional board.
+pub mod board {
+    use crate::generic_full;
+
+    /// Obtains the eight moves of a king, i.e. the two-dimensional Moore neighborhood of range `1`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::board::king_moves;
+    ///
+    /// const MOVES: [[isize; 2]; 8] = king_moves();
+    /// assert_eq!(MOVES[0], [-1, -1]);
+    /// ```
+    pub const fn king_moves() -> [[isize; 2]; 8] {
+        generic_full::moore::<1, 2, 8>()
+    }
+
+    /// Obtains the eight moves of a knight, i.e. the offsets `(±1, ±2)` and `(±2, ±1)`,
+    /// in the same order as [`king_moves`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::board::knight_moves;
+    ///
+    /// const MOVES: [[isize; 2]; 8] = knight_moves();
+    ///
+    /// let expected = [
+    ///               [-1,-2], [ 1,-2],
+    ///     [-2,-1],                    [ 2,-1],
+    ///     [-2, 1],                    [ 2, 1],
+    ///               [-1, 2], [ 1, 2]
+    /// ];
+    ///
+    /// assert_eq!(MOVES, expected);
+    /// ```
+    pub const fn knight_moves() -> [[isize; 2]; 8] {
+        [
+            [-1, -2],
+            [1, -2],
+            [-2, -1],
+            [2, -1],
+            [-2, 1],
+            [2, 1],
+            [-1, 2],
+            [1, 2],
+        ]
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn board_king_moves_are_moore() {
+            assert_eq!(king_moves(), generic_full::moore::<1, 2, 8>());
+        }
+
+        #[test]
+        fn board_knight_moves() {
+            let moves = knight_moves();
+            for [x, y] in moves {
+                let (x, y) = (x.abs(), y.abs());
+                assert!((x, y) == (1, 2) || (x, y) == (2, 1));
+            }
+
+            for (i, a) in moves.iter().enumerate() {
+                assert!(moves[i + 1..].iter().all(|b| a != b));
+            }
+        }
+
+        #[test]
+        fn board_knight_moves_in_scan_order() {
+            let neighbors = generic_full::moore::<2, 2, 24>();
+            let knight = neighbors
+                .into_iter()
+                .filter(|[x, y]| x.abs() + y.abs() == 3);
+            assert!(knight_moves().into_iter().eq(knight));
+        }
+    }
+}
+
+/// Conversions between the `Vec`-based offsets of `dynamic` and the array-based offsets of `generic_dimension`.
+pub mod convert {
+    use crate::MooreError;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    /// Converts the dynamically sized `offset` into an array of `N` coordinates,
+    /// or [`MooreError::LengthMismatch`] if it does not have exactly `N` coordinates.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::convert::to_array;
+    /// use moore_neighborhood::MooreError;
+    ///
+    /// assert_eq!(to_array::<2>(&[-1, 1]), Ok([-1, 1]));
+    /// assert_eq!(
+    ///     to_array::<3>(&[-1, 1]),
+    ///     Err(MooreError::LengthMismatch { expected: 3, got: 2 })
+    /// );
+    /// ```
+    pub fn to_array<const N: usize>(offset: &[isize]) -> Result<[isize; N], MooreError> {
+        offset.try_into().map_err(|_| MooreError::LengthMismatch {
+            expected: N,
+            got: offset.len(),
+        })
+    }
+
+    /// Converts the array-based `offsets` into dynamically sized offsets, keeping their order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::convert::from_arrays;
+    /// use moore_neighborhood::{dynamic, generic_dimension};
+    ///
+    /// let offsets = generic_dimension::moore::<isize, 2>(1);
+    /// assert_eq!(from_arrays(&offsets), dynamic::moore(1u32, 2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_arrays<const N: usize>(offsets: &[[isize; N]]) -> Vec<Vec<isize>> {
+        offsets.iter().map(|offset| offset.to_vec()).collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn convert_to_array_length_mismatch() {
+            assert_eq!(to_array::<0>(&[]), Ok([]));
+            assert_eq!(
+                to_array::<2>(&[1, 2, 3]),
+                Err(MooreError::LengthMismatch {
+                    expected: 2,
+                    got: 3
+                })
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "alloc")]
+        fn convert_round_trip_d3() {
+            use crate::{dynamic, generic_dimension};
+
+            let dynamic = dynamic::moore(1u32, 3);
+            let generic = generic_dimension::moore::<isize, 3>(1);
+
+            let arrays: Vec<[isize; 3]> = dynamic
+                .iter()
+                .map(|offset| to_array(offset).unwrap())
+                .collect();
+            assert_eq!(arrays, generic);
+
+            assert_eq!(from_arrays(&generic), dynamic);
+            assert_eq!(from_arrays(&arrays), dynamic);
+        }
+    }
+}
+
+/// Weighted stencils for finite difference schemes.
+#[cfg(feature = "alloc")]
+pub mod stencil {
+    use crate::von_neumann;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// Obtains the discrete Laplacian stencil in the specified number of `dimensions`, i.e. the Von Neumann
+    /// neighborhood of range `1` with weight `1` and the center cell with weight `-2 * dimensions`.
+    /// The offsets are returned in scan order, with the center cell at its natural position.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::stencil::laplacian;
+    ///
+    /// let result = laplacian(2);
+    ///
+    /// let expected = [
+    ///     (vec![ 0,-1],  1.0),
+    ///     (vec![-1, 0],  1.0),
+    ///     (vec![ 0, 0], -4.0),
+    ///     (vec![ 1, 0],  1.0),
+    ///     (vec![ 0, 1],  1.0),
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn laplacian(dimensions: u32) -> Vec<(Vec<isize>, f64)> {
+        let mut stencil: Vec<(Vec<isize>, f64)> = von_neumann::neighborhood(1, dimensions)
+            .into_iter()
+            .map(|offset| (offset, 1.0))
+            .collect();
+
+        // The neighborhood is symmetric around the center, which hence lies right in the middle.
+        let center = (vec![0; dimensions as _], -2.0 * dimensions as f64);
+        stencil.insert(stencil.len() / 2, center);
+        stencil
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Obtains the weight of the center cell of the `stencil`.
+        fn center_weight(stencil: &[(Vec<isize>, f64)]) -> f64 {
+            let (_, weight) = stencil
+                .iter()
+                .find(|(offset, _)| offset.iter().all(|&c| c == 0))
+                .unwrap();
+            *weight
+        }
+
+        #[test]
+        fn stencil_laplacian_d2_five_point() {
+            let stencil = laplacian(2);
+            assert_eq!(stencil.len(), 5);
+            assert_eq!(center_weight(&stencil), -4.0);
+
+            let neighbors: Vec<&Vec<isize>> = stencil
+                .iter()
+                .filter(|(_, weight)| *weight == 1.0)
+                .map(|(offset, _)| offset)
+                .collect();
+            assert_eq!(neighbors, [&[0, -1], &[-1, 0], &[1, 0], &[0, 1]]);
+        }
+
+        #[test]
+        fn stencil_laplacian_d3() {
+            let stencil = laplacian(3);
+            assert_eq!(stencil.len(), 7);
+            assert_eq!(center_weight(&stencil), -6.0);
+            assert_eq!(stencil[3], (vec![0, 0, 0], -6.0));
+        }
+
+        #[test]
+        fn stencil_laplacian_weights_sum_to_zero() {
+            for dimensions in 1..=5 {
+                let sum: f64 = laplacian(dimensions).iter().map(|(_, weight)| weight).sum();
+                assert_eq!(sum, 0.0);
+            }
+        }
+    }
+}
+
+/// Precomputed neighborhoods for the most common cases, evaluated at compile time by [`generic_full`].
+pub mod presets {
+    use crate::generic_full;
+
+    /// The Moore neighborhood of range `1` in two dimensions, i.e. `generic_full::moore::<1, 2, 8>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::presets::MOORE_2D_R1;
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(MOORE_2D_R1, expected);
+    /// ```
+    pub const MOORE_2D_R1: [[isize; 2]; 8] = generic_full::moore::<1, 2, 8>();
+
+    /// The Moore neighborhood of range `1` in two dimensions including the center,
+    /// i.e. `generic_full::moore_with_center::<1, 2, 9>()`.
+    pub const MOORE_2D_R1_WITH_CENTER: [[isize; 2]; 9] =
+        generic_full::moore_with_center::<1, 2, 9>();
+
+    /// The Moore neighborhood of range `1` in three dimensions, i.e. `generic_full::moore::<1, 3, 26>()`.
+    pub const MOORE_3D_R1: [[isize; 3]; 26] = generic_full::moore::<1, 3, 26>();
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn presets_match_generic_full() {
+            assert_eq!(MOORE_2D_R1, generic_full::moore::<1, 2, 8>());
+            assert_eq!(
+                MOORE_2D_R1_WITH_CENTER,
+                generic_full::moore_with_center::<1, 2, 9>()
+            );
+            assert_eq!(MOORE_3D_R1, generic_full::moore::<1, 3, 26>());
+        }
+
+        #[test]
+        fn presets_2d_r1_with_center() {
             #[rustfmt::skip]
             let expected = [
-                [-2, -2], [-1, -2], [ 0, -2], [ 1, -2], [ 2, -2],
-                [-2, -1], [-1, -1], [ 0, -1], [ 1, -1], [ 2, -1],
-                [-2,  0], [-1,  0],           [ 1,  0], [ 2,  0],
-                [-2,  1], [-1,  1], [ 0,  1], [ 1,  1], [ 2,  1],
-                [-2,  2], [-1,  2], [ 0,  2], [ 1,  2], [ 2,  2]
+                [-1,-1], [ 0,-1], [ 1,-1],
+                [-1, 0], [ 0, 0], [ 1, 0],
+                [-1, 1], [ 0, 1], [ 1, 1]
             ];
 
-            assert_eq!(result, expected);
+            assert_eq!(MOORE_2D_R1_WITH_CENTER, expected);
+        }
+    }
+}
+
+/// Text rendering of neighborhoods for debugging and documentation.
+#[cfg(feature = "alloc")]
+pub mod fmt {
+    use alloc::string::String;
+
+    /// Renders the two-dimensional Moore neighborhood for a region of width `range` as a grid of text,
+    /// showing the neighbors as `#` and the center cell as `O`. Rows are separated by newlines,
+    /// with the first row corresponding to the smallest `y` coordinate.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::fmt::format_2d;
+    ///
+    /// assert_eq!(format_2d(1), "###\n#O#\n###");
+    /// ```
+    pub fn format_2d(range: u32) -> String {
+        let extent = range as isize;
+        let size = range as usize * 2 + 1;
+        let mut grid = String::with_capacity(size * (size + 1));
+
+        for y in -extent..=extent {
+            if y > -extent {
+                grid.push('\n');
+            }
+            for x in -extent..=extent {
+                grid.push(if x == 0 && y == 0 { 'O' } else { '#' });
+            }
+        }
+        grid
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn fmt_2d_r1_works() {
+            let expected = "\
+###
+#O#
+###";
+            assert_eq!(format_2d(1), expected);
+        }
+
+        #[test]
+        fn fmt_2d_r2_works() {
+            let expected = "\
+#####
+#####
+##O##
+#####
+#####";
+            assert_eq!(format_2d(2), expected);
+        }
+
+        #[test]
+        fn fmt_2d_r0_works() {
+            assert_eq!(format_2d(0), "O");
+        }
+    }
+}
+
+/// Moore neighborhoods as [`glam`](::glam) integer vectors.
+#[cfg(feature = "glam")]
+pub mod glam {
+    use crate::generic_dimension;
+    use ::glam::{IVec2, IVec3};
+    use alloc::vec::Vec;
+
+    /// Obtains the two-dimensional Moore neighborhood for a region of width `range` as [`IVec2`] offsets,
+    /// in the same order as [`generic_dimension::moore`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use glam::IVec2;
+    /// use moore_neighborhood::glam::moore_ivec2;
+    ///
+    /// let result = moore_ivec2(1);
+    /// assert_eq!(result.len(), 8);
+    /// assert_eq!(result[0], IVec2::new(-1, -1));
+    /// ```
+    pub fn moore_ivec2(range: u32) -> Vec<IVec2> {
+        debug_assert!(range <= i32::MAX as u32, "range exceeds i32");
+        generic_dimension::moore::<i32, 2>(range)
+            .into_iter()
+            .map(IVec2::from)
+            .collect()
+    }
+
+    /// Obtains the three-dimensional Moore neighborhood for a region of width `range` as [`IVec3`] offsets,
+    /// in the same order as [`generic_dimension::moore`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use glam::IVec3;
+    /// use moore_neighborhood::glam::moore_ivec3;
+    ///
+    /// let result = moore_ivec3(1);
+    /// assert_eq!(result.len(), 26);
+    /// assert_eq!(result[0], IVec3::new(-1, -1, -1));
+    /// ```
+    pub fn moore_ivec3(range: u32) -> Vec<IVec3> {
+        debug_assert!(range <= i32::MAX as u32, "range exceeds i32");
+        generic_dimension::moore::<i32, 3>(range)
+            .into_iter()
+            .map(IVec3::from)
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn glam_ivec2_same_as_moore() {
+            let expected = generic_dimension::moore::<isize, 2>(2);
+            let result = moore_ivec2(2);
+            assert_eq!(result.len(), expected.len());
+            for (vector, offset) in result.iter().zip(&expected) {
+                assert_eq!([vector.x as isize, vector.y as isize], *offset);
+            }
+        }
+
+        #[test]
+        fn glam_ivec3_same_as_moore() {
+            let expected = generic_dimension::moore::<isize, 3>(2);
+            let result = moore_ivec3(2);
+            assert_eq!(result.len(), expected.len());
+            for (vector, offset) in result.iter().zip(&expected) {
+                assert_eq!(
+                    [vector.x as isize, vector.y as isize, vector.z as isize],
+                    *offset
+                );
+            }
+        }
+    }
+}
+
+/// Moore neighborhoods as [`nalgebra`] integer vectors.
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_ext {
+    use crate::generic_dimension;
+    use alloc::vec::Vec;
+    use nalgebra::SVector;
+
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `D` dimensions
+    /// as [`SVector`] offsets, in the same order as [`generic_dimension::moore`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use nalgebra::Vector2;
+    /// use moore_neighborhood::nalgebra_ext::moore_svector;
+    ///
+    /// let result = moore_svector::<2>(1);
+    /// assert_eq!(result.len(), 8);
+    /// assert_eq!(result[0], Vector2::new(-1, -1));
+    /// ```
+    pub fn moore_svector<const D: usize>(range: u32) -> Vec<SVector<i32, D>> {
+        debug_assert!(range <= i32::MAX as u32, "range exceeds i32");
+        generic_dimension::moore::<i32, D>(range)
+            .into_iter()
+            .map(SVector::from)
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn nalgebra_svector_d2_same_as_moore() {
+            let expected = generic_dimension::moore::<isize, 2>(2);
+            let result = moore_svector::<2>(2);
+            assert_eq!(result.len(), expected.len());
+            for (vector, offset) in result.iter().zip(&expected) {
+                assert_eq!([vector.x as isize, vector.y as isize], *offset);
+            }
         }
 
         #[test]
-        fn gen_dim_d3_r1_works() {
-            let result = moore::<3>(1);
+        fn nalgebra_svector_d3_same_as_moore() {
+            let expected = generic_dimension::moore::<isize, 3>(1);
+            let result = moore_svector::<3>(1);
+            assert_eq!(result.len(), expected.len());
+            for (vector, offset) in result.iter().zip(&expected) {
+                let components: Vec<isize> = vector.iter().map(|&c| c as isize).collect();
+                assert_eq!(components, offset);
+            }
+        }
+    }
+}
 
-            #[rustfmt::skip]
-            let expected = [
-                [-1, -1, -1], [ 0, -1, -1], [ 1, -1, -1],
-                [-1,  0, -1], [ 0,  0, -1], [ 1,  0, -1],
-                [-1,  1, -1], [ 0,  1, -1], [ 1,  1, -1],
+/// Moore neighborhoods as [`ndarray`] arrays.
+#[cfg(feature = "ndarray")]
+pub mod ndarray_ext {
+    use crate::dynamic;
+    use ndarray::Array2;
 
-                [-1, -1,  0], [ 0, -1,  0], [ 1, -1,  0],
-                [-1,  0,  0],               [ 1,  0,  0],
-                [-1,  1,  0], [ 0,  1,  0], [ 1,  1,  0],
+    /// Obtains the Moore neighborhood for a region of width `range` for in the specified number of `dimensions`
+    /// as an array of shape `(count, dimensions)`, where each row is one neighbor in the order of [`dynamic::moore`].
+    /// The array is built from the buffer of [`dynamic::moore_flat`] without intermediate allocations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::ndarray_ext::moore_array2;
+    ///
+    /// let result = moore_array2(1, 2);
+    /// assert_eq!(result.shape(), [8, 2]);
+    /// assert_eq!(result.row(0).to_vec(), [-1, -1]);
+    /// ```
+    pub fn moore_array2(range: u32, dimensions: u32) -> Array2<isize> {
+        let (buffer, stride) = dynamic::moore_flat(range, dimensions);
+        let count = dynamic::moore_count(range, dimensions);
+        Array2::from_shape_vec((count, stride), buffer)
+            .expect("the flat buffer matches the neighborhood shape")
+    }
 
-                [-1, -1,  1], [ 0, -1,  1], [ 1, -1,  1],
-                [-1,  0,  1], [ 0,  0,  1], [ 1,  0,  1],
-                [-1,  1,  1], [ 0,  1,  1], [ 1,  1,  1]
-            ];
+    #[cfg(test)]
+    mod tests {
+        use super::*;
 
-            assert_eq!(result, expected);
+        #[test]
+        fn ndarray_array2_same_as_moore() {
+            let expected = dynamic::moore(2u32, 3);
+            let result = moore_array2(2, 3);
+            assert_eq!(result.shape(), [124, 3]);
+            assert_eq!(result.row(0).to_vec(), expected[0]);
+            for (row, offset) in result.rows().into_iter().zip(&expected) {
+                assert_eq!(row.to_vec(), *offset);
+            }
+        }
+
+        #[test]
+        fn ndarray_array2_range_0_is_empty() {
+            assert_eq!(moore_array2(0, 2).shape(), [0, 2]);
         }
     }
 }
 
 /// Fully generic Moore neighborhoods for statically known ranges and dimensionality.
 pub mod generic_full {
+    use crate::{Decoder, MooreError};
+
+    /// Determines the number of neighbors of the Moore neighborhood for a region of width `range`
+    /// in the specified number of `dimensions`, i.e. `(2*range+1).pow(dimensions) - 1`.
+    /// This is the `LENGTH` expected by [`moore()`] and can be evaluated at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::{moore, moore_length};
+    ///
+    /// const RANGE: u32 = 2;
+    /// const DIMENSIONS: usize = 3;
+    ///
+    /// let result = moore::<RANGE, DIMENSIONS, { moore_length(RANGE, DIMENSIONS) }>();
+    /// assert_eq!(result.len(), 124);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the number of neighbors does not fit into a `usize`, in release builds as well.
+    /// In const context, e.g. for [`Moore::COUNT`], this is a compile error.
+    pub const fn moore_length(range: u32, dimensions: usize) -> usize {
+        let size = match (range as usize).checked_mul(2) {
+            Some(width) => width + 1,
+            None => panic!("the neighborhood length overflows usize"),
+        };
+        if dimensions > u32::MAX as usize {
+            panic!("the neighborhood length overflows usize");
+        }
+
+        match size.checked_pow(dimensions as u32) {
+            Some(cells) => cells - 1,
+            None => panic!("the neighborhood length overflows usize"),
+        }
+    }
+
     /// Obtains the Moore neighborhood for a region of width `RANGE` for in the specified number of `DIMENSIONS`.
     /// The returned array has length `LENGTH`, which is determined as `(2*RANGE+1).pow(DIMENSIONS) - 1`.
     ///
@@ -324,21 +6665,66 @@ pub mod generic_full {
     ///
     /// assert_eq!(result, expected);
     /// ```
+    ///
+    /// The function can be evaluated at compile time:
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::moore;
+    ///
+    /// const NEIGHBORS: [[isize; 2]; 8] = moore::<1, 2, 8>();
+    /// assert_eq!(NEIGHBORS[0], [-1, -1]);
+    /// ```
+    ///
+    /// A `RANGE` of `0` yields an empty array, i.e. `moore::<0, DIMENSIONS, 0>()`.
+    ///
+    /// The neighbors are returned in the same scan order as `dynamic::moore` and
+    /// `generic_dimension::moore`, with the first coordinate varying fastest. This order is guaranteed.
     #[inline]
-    pub fn moore<const RANGE: u32, const DIMENSIONS: usize, const LENGTH: usize>(
+    pub const fn moore<const RANGE: u32, const DIMENSIONS: usize, const LENGTH: usize>(
     ) -> [[isize; DIMENSIONS]; LENGTH] {
         assert!(DIMENSIONS < u32::MAX as _);
 
-        {
-            let size: usize = RANGE as usize * 2 + 1;
-            debug_assert_eq!(LENGTH, size.pow(DIMENSIONS as _) - 1);
-        }
+        debug_assert!(LENGTH == moore_length(RANGE, DIMENSIONS));
 
         let mut neighbors = [[0isize; DIMENSIONS]; LENGTH];
         moore_prealloc::<RANGE, DIMENSIONS, LENGTH>(&mut neighbors);
         neighbors
     }
 
+    /// Obtains the Moore neighborhood for a region of width `RANGE` for in the specified number of `DIMENSIONS`,
+    /// or [`MooreError::LengthMismatch`] if `LENGTH` is not `(2*RANGE+1).pow(DIMENSIONS) - 1`.
+    /// Unlike [`moore()`], the length is validated in all build profiles.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::{moore, try_moore};
+    /// use moore_neighborhood::MooreError;
+    ///
+    /// assert_eq!(try_moore::<1, 2, 8>(), Ok(moore::<1, 2, 8>()));
+    /// assert_eq!(
+    ///     try_moore::<1, 2, 9>(),
+    ///     Err(MooreError::LengthMismatch { expected: 8, got: 9 })
+    /// );
+    /// ```
+    pub const fn try_moore<const RANGE: u32, const DIMENSIONS: usize, const LENGTH: usize>(
+    ) -> Result<[[isize; DIMENSIONS]; LENGTH], MooreError> {
+        let size: usize = RANGE as usize * 2 + 1;
+        let expected = match size.checked_pow(DIMENSIONS as _) {
+            Some(count) => count - 1,
+            None => return Err(MooreError::LengthOverflow),
+        };
+
+        if LENGTH != expected {
+            return Err(MooreError::LengthMismatch {
+                expected,
+                got: LENGTH,
+            });
+        }
+
+        Ok(moore::<RANGE, DIMENSIONS, LENGTH>())
+    }
+
     /// Obtains the Moore neighborhood for a region of width `RANGE` for in the specified number of `DIMENSIONS`.
     /// The provided array needs to have a length of at least `LENGTH`, which is required to be `(2*RANGE+1).pow(DIMENSIONS) - 1`.
     ///
@@ -359,34 +6745,251 @@ pub mod generic_full {
     /// assert_eq!(length, 8);
     /// assert_eq!(neighbors, expected);
     /// ```
-    pub fn moore_prealloc<const RANGE: u32, const DIMENSIONS: usize, const LENGTH: usize>(
+    pub const fn moore_prealloc<const RANGE: u32, const DIMENSIONS: usize, const LENGTH: usize>(
         neighbors: &mut [[isize; DIMENSIONS]; LENGTH],
     ) -> usize {
         assert!(DIMENSIONS < u32::MAX as _);
 
         let size: usize = RANGE as usize * 2 + 1;
-        let length = size.pow(DIMENSIONS as _) - 1;
+        let length = moore_length(RANGE, DIMENSIONS);
         debug_assert!(LENGTH >= length);
 
-        let half_length = LENGTH / 2;
-        for (i, neighbor) in neighbors.iter_mut().enumerate().take(LENGTH) {
-            let mut index = if i < half_length { i } else { i + 1 };
-            let mut prev_divisor = 1;
-            for dimension in neighbor.iter_mut().take(DIMENSIONS) {
-                let divisor = prev_divisor * size;
-                let value = index % divisor;
-                *dimension = (value / prev_divisor) as isize - RANGE as isize;
-                prev_divisor = divisor;
-                index -= value;
-            }
+        // Iterators are not available in const context, hence the explicit loop.
+        let decoder = Decoder {
+            size,
+            dimensions: DIMENSIONS,
+            half_length: LENGTH / 2,
+        };
+        let mut i = 0;
+        while i < LENGTH {
+            decoder.decode_into(i, &mut neighbors[i]);
+            i += 1;
         }
         length
     }
 
+    /// Obtains the Moore neighborhood for a region of width `RANGE` for in the specified number of `DIMENSIONS`,
+    /// including the center cell. The returned array has length `LENGTH`, which is determined as
+    /// `(2*RANGE+1).pow(DIMENSIONS)`; the center cell is located at index `LENGTH / 2`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::moore_with_center;
+    ///
+    /// let result: [[isize; 2]; 9] = moore_with_center::<1, 2, 9>();
+    ///
+    /// let expected = [
+    ///     [-1,-1], [ 0,-1], [ 1,-1],
+    ///     [-1, 0], [ 0, 0], [ 1, 0],
+    ///     [-1, 1], [ 0, 1], [ 1, 1]
+    /// ];
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub const fn moore_with_center<
+        const RANGE: u32,
+        const DIMENSIONS: usize,
+        const LENGTH: usize,
+    >() -> [[isize; DIMENSIONS]; LENGTH] {
+        assert!(DIMENSIONS < u32::MAX as _);
+
+        let decoder = Decoder::new(RANGE, DIMENSIONS as _);
+        debug_assert!(LENGTH == decoder.len() + 1);
+
+        // Iterators are not available in const context, hence the explicit loop.
+        let mut neighbors = [[0isize; DIMENSIONS]; LENGTH];
+        let mut i = 0;
+        while i < LENGTH {
+            decoder.decode_cell_into(i, &mut neighbors[i]);
+            i += 1;
+        }
+        neighbors
+    }
+
+    /// Obtains the face neighbors in the specified number of `DIMENSIONS`, i.e. the `2*DIMENSIONS` unit offsets
+    /// along each axis, or the Von Neumann neighborhood of range `1`. The returned array has length `LENGTH`,
+    /// which is required to be `2*DIMENSIONS`. The offsets are returned in the same order as [`moore()`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::moore_faces;
+    ///
+    /// const FACES: [[isize; 2]; 4] = moore_faces::<2, 4>();
+    ///
+    /// let expected = [
+    ///              [ 0,-1],
+    ///     [-1, 0],          [ 1, 0],
+    ///              [ 0, 1]
+    /// ];
+    ///
+    /// assert_eq!(FACES, expected);
+    /// ```
+    pub const fn moore_faces<const DIMENSIONS: usize, const LENGTH: usize>(
+    ) -> [[isize; DIMENSIONS]; LENGTH] {
+        debug_assert!(LENGTH == 2 * DIMENSIONS);
+
+        // The negative offsets precede the center cell, from the slowest-moving axis to the fastest;
+        // the positive offsets follow it in the opposite order.
+        let mut faces = [[0isize; DIMENSIONS]; LENGTH];
+        let mut i = 0;
+        while i < DIMENSIONS {
+            faces[i][DIMENSIONS - 1 - i] = -1;
+            faces[DIMENSIONS + i][i] = 1;
+            i += 1;
+        }
+        faces
+    }
+
+    /// A type-level handle for the Moore neighborhood of width `RANGE` in the specified number of `DIMENSIONS`,
+    /// allowing generic code to be written over a neighborhood.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use moore_neighborhood::generic_full::Moore;
+    ///
+    /// type Neighborhood = Moore<1, 2>;
+    ///
+    /// const OFFSETS: [[isize; 2]; Neighborhood::COUNT] = Neighborhood::offsets();
+    /// assert_eq!(OFFSETS[0], [-1, -1]);
+    /// ```
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Moore<const RANGE: u32, const DIMENSIONS: usize>;
+
+    impl<const RANGE: u32, const DIMENSIONS: usize> Moore<RANGE, DIMENSIONS> {
+        /// The width of the neighborhood.
+        pub const RANGE: u32 = RANGE;
+
+        /// The number of dimensions.
+        pub const DIMENSIONS: usize = DIMENSIONS;
+
+        /// The number of neighbors, i.e. `(2*RANGE+1).pow(DIMENSIONS) - 1`.
+        pub const COUNT: usize = moore_length(RANGE, DIMENSIONS);
+
+        /// Obtains the neighbors as an array of length `LENGTH`, which is required to be [`Self::COUNT`].
+        /// As the length cannot be derived from the type parameters on stable Rust, it must be specified
+        /// explicitly, usually as `Moore::<RANGE, DIMENSIONS>::COUNT` or by inference.
+        ///
+        /// ## Panics
+        ///
+        /// Panics if `LENGTH` differs from [`Self::COUNT`]. In a const context, this is a compile-time error.
+        pub const fn offsets<const LENGTH: usize>() -> [[isize; DIMENSIONS]; LENGTH] {
+            assert!(LENGTH == Self::COUNT, "LENGTH must equal Moore::COUNT");
+            moore::<RANGE, DIMENSIONS, LENGTH>()
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
 
+        #[test]
+        fn gen_x_faces_d2() {
+            const FACES: [[isize; 2]; 4] = moore_faces::<2, 4>();
+            assert_eq!(FACES, [[0, -1], [-1, 0], [1, 0], [0, 1]]);
+        }
+
+        #[test]
+        fn gen_x_faces_d3() {
+            let faces = moore_faces::<3, 6>();
+
+            #[rustfmt::skip]
+            let expected = [
+                [ 0, 0,-1],
+                [ 0,-1, 0], [-1, 0, 0], [ 1, 0, 0], [ 0, 1, 0],
+                [ 0, 0, 1]
+            ];
+
+            assert_eq!(faces, expected);
+        }
+
+        #[test]
+        fn gen_x_faces_is_subset_of_moore() {
+            let neighbors = moore::<1, 4, 80>();
+            let faces = moore_faces::<4, 8>();
+
+            let unit_offsets = neighbors
+                .into_iter()
+                .filter(|n| n.iter().map(|c| c.abs()).sum::<isize>() == 1);
+            assert!(faces.into_iter().eq(unit_offsets));
+        }
+
+        #[test]
+        fn gen_x_moore_length_in_const_context() {
+            const LENGTHS: [usize; 5] = [
+                moore_length(0, 3),
+                moore_length(1, 1),
+                moore_length(1, 2),
+                moore_length(2, 3),
+                moore_length(3, 4),
+            ];
+            let parameters = [(0, 3), (1, 1), (1, 2), (2, 3), (3, 4)];
+
+            for (length, (range, dimensions)) in LENGTHS.into_iter().zip(parameters) {
+                assert_eq!(length, Decoder::new(range, dimensions).len());
+            }
+
+            const NEIGHBORS: [[isize; 2]; moore_length(2, 2)] =
+                moore::<2, 2, { moore_length(2, 2) }>();
+            assert_eq!(NEIGHBORS.len(), 24);
+        }
+
+        #[test]
+        #[should_panic(expected = "the neighborhood length overflows usize")]
+        fn gen_x_moore_length_overflow() {
+            moore_length(core::hint::black_box(u32::MAX), core::hint::black_box(3));
+        }
+
+        #[test]
+        fn gen_x_marker_count() {
+            assert_eq!(Moore::<1, 2>::COUNT, 8);
+            assert_eq!(Moore::<2, 3>::COUNT, 124);
+            assert_eq!(Moore::<0, 4>::COUNT, 0);
+            assert_eq!(Moore::<2, 3>::RANGE, 2);
+            assert_eq!(Moore::<2, 3>::DIMENSIONS, 3);
+        }
+
+        #[test]
+        fn gen_x_marker_offsets() {
+            const OFFSETS: [[isize; 3]; Moore::<1, 3>::COUNT] = Moore::<1, 3>::offsets();
+            assert_eq!(OFFSETS, moore::<1, 3, 26>());
+
+            let offsets: [[isize; 2]; 24] = Moore::<2, 2>::offsets();
+            assert_eq!(offsets, moore::<2, 2, 24>());
+        }
+
+        #[test]
+        fn gen_x_marker_in_generic_code() {
+            fn first<const R: u32>() -> [isize; 2] {
+                Moore::<R, 2>::offsets::<8>()[0]
+            }
+            assert_eq!(first::<1>(), [-1, -1]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn gen_x_marker_length_mismatch_panics() {
+            let _ = Moore::<1, 2>::offsets::<9>();
+        }
+
+        #[test]
+        fn gen_x_with_center_d1_r2_works() {
+            const NEIGHBORS: [[isize; 1]; 5] = moore_with_center::<2, 1, 5>();
+            assert_eq!(NEIGHBORS, [[-2], [-1], [0], [1], [2]]);
+        }
+
+        #[test]
+        fn gen_x_with_center_contains_moore() {
+            let with_center = moore_with_center::<2, 3, 125>();
+            let neighbors = moore::<2, 3, 124>();
+
+            assert_eq!(with_center[62], [0, 0, 0]);
+            assert_eq!(with_center[..62], neighbors[..62]);
+            assert_eq!(with_center[63..], neighbors[62..]);
+        }
+
         #[test]
         fn gen_x_d2_r1_works() {
             let result: [[isize; 2]; 8] = moore::<1, 2, 8>();
@@ -401,6 +7004,51 @@ pub mod generic_full {
             assert_eq!(result, expected);
         }
 
+        #[test]
+        fn gen_x_const_same_as_runtime() {
+            const NEIGHBORS_2D: [[isize; 2]; 8] = moore::<1, 2, 8>();
+            const NEIGHBORS_3D: [[isize; 3]; 124] = moore::<2, 3, 124>();
+
+            assert_eq!(NEIGHBORS_2D, moore::<1, 2, 8>());
+            assert_eq!(NEIGHBORS_3D, moore::<2, 3, 124>());
+        }
+
+        #[test]
+        fn gen_x_range_0_is_empty() {
+            let result: [[isize; 1]; 0] = moore::<0, 1, 0>();
+            assert!(result.is_empty());
+
+            let result: [[isize; 3]; 0] = moore::<0, 3, 0>();
+            assert!(result.is_empty());
+
+            let mut neighbors = [[0isize; 2]; 0];
+            assert_eq!(moore_prealloc::<0, 2, 0>(&mut neighbors), 0);
+        }
+
+        #[test]
+        fn gen_x_try_moore_length_mismatch() {
+            assert_eq!(
+                try_moore::<1, 2, 7>(),
+                Err(MooreError::LengthMismatch {
+                    expected: 8,
+                    got: 7
+                })
+            );
+            assert_eq!(
+                try_moore::<1, 3, 27>(),
+                Err(MooreError::LengthMismatch {
+                    expected: 26,
+                    got: 27
+                })
+            );
+            assert_eq!(try_moore::<1, 3, 26>(), Ok(moore::<1, 3, 26>()));
+        }
+
+        #[test]
+        fn gen_x_try_moore_length_overflow() {
+            assert_eq!(try_moore::<1, 100, 0>(), Err(MooreError::LengthOverflow));
+        }
+
         #[test]
         fn gen_x_d3_r1_works() {
             let result = moore::<1, 3, 26>();
@@ -427,7 +7075,92 @@ pub mod generic_full {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn alloc_vec_based_modules_work() {
+        use alloc::vec::Vec;
+
+        let dynamic: Vec<Vec<isize>> = crate::dynamic::moore(1u32, 2);
+        let generic: Vec<[isize; 2]> = crate::generic_dimension::moore(1);
+        let full: [[isize; 2]; 8] = moore!(1, 2);
+
+        assert_eq!(dynamic, full);
+        assert_eq!(generic, full);
+    }
+
+    #[test]
+    fn decoder_skips_center() {
+        let decoder = crate::Decoder::new(1, 2);
+        assert_eq!(decoder.size(), 3);
+        assert_eq!(decoder.dimensions(), 2);
+        assert_eq!(decoder.half_length(), 4);
+        assert_eq!(decoder.len(), 8);
+
+        assert_eq!(decoder.raster_index(3), 3);
+        assert_eq!(decoder.raster_index(4), 5);
+        assert!(decoder.decode(3).eq([-1, 0]));
+        assert!(decoder.decode(4).eq([1, 0]));
+        assert!(decoder.decode_cell(4).eq([0, 0]));
+    }
+
+    #[test]
+    fn decoder_into_matches_iterator() {
+        let decoder = crate::Decoder::new(2, 3);
+        let mut neighbor = [0; 3];
+        for index in 0..decoder.len() {
+            decoder.decode_into(index, &mut neighbor);
+            assert!(decoder.decode(index).eq(neighbor));
+
+            decoder.decode_cell_into(index, &mut neighbor);
+            assert!(decoder.decode_cell(index).eq(neighbor));
+        }
+
+        decoder.decode_into(decoder.len() - 1, &mut neighbor);
+        assert_eq!(neighbor, [2, 2, 2]);
+    }
+
+    #[test]
+    fn decoder_range_0_is_empty() {
+        let decoder = crate::Decoder::new(0, 4);
+        assert!(decoder.is_empty());
+        assert_eq!(decoder.len(), 0);
+        assert!(decoder.decode_cell(0).eq([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn decoder_in_const_context() {
+        const NEIGHBOR: [isize; 2] = {
+            let mut neighbor = [0; 2];
+            crate::Decoder::new(1, 2).decode_into(7, &mut neighbor);
+            neighbor
+        };
+        assert_eq!(NEIGHBOR, [1, 1]);
+    }
+
+    #[test]
+    fn decoder_encode_inverts_decode() {
+        let decoder = crate::Decoder::new(2, 3);
+        let mut cell = [0; 3];
+        for raster in 0..=decoder.len() {
+            decoder.decode_cell_into(raster, &mut cell);
+            assert_eq!(decoder.encode_cell(&cell), Some(raster));
+        }
+        for index in 0..decoder.len() {
+            decoder.decode_into(index, &mut cell);
+            assert_eq!(decoder.encode(&cell), Some(index));
+        }
+
+        assert_eq!(decoder.encode(&[0, 0, 0]), None);
+        assert_eq!(decoder.encode_cell(&[0, 0, 0]), Some(decoder.half_length()));
+        assert_eq!(decoder.encode_cell(&[3, 0, 0]), None);
+        assert_eq!(decoder.encode_cell(&[0, 0]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn decoder_overflow_panics() {
+        crate::Decoder::new(1, 100);
+    }
 
     #[test]
     fn macro_d1_r1_works() {
@@ -436,6 +7169,49 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn macro_with_center_d1_r1_works() {
+        let result: [[isize; 1]; 3] = moore!(1, 1; with_center);
+        assert_eq!(result[1], [0]);
+        assert_eq!(result, [[-1], [0], [1]]);
+    }
+
+    #[test]
+    fn macro_with_center_d2_r2_works() {
+        let result: [[isize; 2]; 25] = moore!(2, 2; with_center);
+        assert_eq!(result[12], [0, 0]);
+        assert_eq!(result[0], [-2, -2]);
+        assert_eq!(result[24], [2, 2]);
+    }
+
+    #[test]
+    fn macro_named_constants() {
+        const R: u32 = 2;
+        const D: usize = 3;
+
+        let result: [[isize; 3]; 124] = moore!(R, D);
+        assert_eq!(result, crate::generic_full::moore::<2, 3, 124>());
+
+        let result: [[isize; 2]; 8] = moore!(R - 1);
+        assert_eq!(result, moore!());
+
+        let result: [[isize; 1]; 5] = moore!(R, D - 2; with_center);
+        assert_eq!(result, [[-2], [-1], [0], [1], [2]]);
+    }
+
+    #[test]
+    fn macro_constants_named_like_internals() {
+        const RANGE: u32 = 1;
+        const DIMS: usize = 2;
+        const NUM_FIELDS: usize = 3;
+
+        let result: [[isize; 2]; 8] = moore!(RANGE, DIMS);
+        assert_eq!(result, moore!(1, 2));
+
+        let result: [[isize; 1]; 2] = moore!(RANGE, NUM_FIELDS - 2);
+        assert_eq!(result, [[-1], [1]]);
+    }
+
     #[test]
     fn macro_d2_r1_works() {
         let result: [[isize; 2]; 8] = moore!(1, 2);
@@ -450,3 +7226,87 @@ mod tests {
         assert_eq!(result, expected);
     }
 }
+
+/// Asserts that all modules generate their neighborhoods in the same scan order.
+#[cfg(all(test, feature = "alloc"))]
+mod ordering_tests {
+    use alloc::vec::Vec;
+
+    /// Compares the neighborhoods of `dynamic`, `generic_dimension` and `generic_full`
+    /// element-wise for a statically known range and dimensionality.
+    macro_rules! assert_same_order {
+        ($range: literal, $dims: literal) => {{
+            const RANGE: u32 = $range;
+            const LENGTH: usize = crate::generic_full::moore_length(RANGE, $dims);
+
+            let dynamic: Vec<Vec<isize>> = crate::dynamic::moore(RANGE, $dims);
+            let generic: Vec<[isize; $dims]> = crate::generic_dimension::moore($range);
+            let full: [[isize; $dims]; LENGTH] =
+                crate::generic_full::moore::<$range, $dims, LENGTH>();
+
+            assert_eq!(
+                dynamic.len(),
+                LENGTH,
+                "range {}, dimensions {}",
+                $range,
+                $dims
+            );
+            assert_eq!(
+                generic.len(),
+                LENGTH,
+                "range {}, dimensions {}",
+                $range,
+                $dims
+            );
+            for (index, expected) in full.iter().enumerate() {
+                assert_eq!(
+                    &dynamic[index][..],
+                    &expected[..],
+                    "index {index} of ({}, {})",
+                    $range,
+                    $dims
+                );
+                assert_eq!(
+                    &generic[index], expected,
+                    "index {index} of ({}, {})",
+                    $range, $dims
+                );
+            }
+        }};
+    }
+
+    #[test]
+    fn ordering_range_0() {
+        assert_same_order!(0, 1);
+        assert_same_order!(0, 2);
+        assert_same_order!(0, 3);
+    }
+
+    #[test]
+    fn ordering_1d() {
+        assert_same_order!(1, 1);
+        assert_same_order!(2, 1);
+        assert_same_order!(5, 1);
+    }
+
+    #[test]
+    fn ordering_2d() {
+        assert_same_order!(1, 2);
+        assert_same_order!(2, 2);
+        assert_same_order!(3, 2);
+    }
+
+    #[test]
+    fn ordering_3d() {
+        assert_same_order!(1, 3);
+        assert_same_order!(2, 3);
+        assert_same_order!(3, 3);
+    }
+
+    #[test]
+    fn ordering_higher_dimensions() {
+        assert_same_order!(1, 4);
+        assert_same_order!(2, 4);
+        assert_same_order!(1, 5);
+    }
+}
@@ -0,0 +1,7 @@
+//! Verifies that misuse of the compile-time checked APIs is rejected by the compiler.
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,38 @@
+//! Verifies that the `moore!` macro initializes constants in a `no_std` crate,
+//! i.e. that its expansion is a constant expression that requires neither `std` nor an allocator.
+
+#![no_std]
+
+use moore_neighborhood::moore;
+
+const NEIGHBORS: [[isize; 2]; 8] = moore!(1, 2);
+const DEFAULT: [[isize; 2]; 8] = moore!();
+const BLOCK: [[isize; 3]; 27] = moore!(1, 3; with_center);
+
+static LARGE: [[isize; 2]; 24] = moore!(2);
+
+#[test]
+fn no_std_macro_in_const() {
+    #[rustfmt::skip]
+    let expected = [
+        [-1,-1], [ 0,-1], [ 1,-1],
+        [-1, 0],          [ 1, 0],
+        [-1, 1], [ 0, 1], [ 1, 1]
+    ];
+
+    assert_eq!(NEIGHBORS, expected);
+    assert_eq!(DEFAULT, expected);
+}
+
+#[test]
+fn no_std_macro_with_center_in_const() {
+    assert_eq!(BLOCK[13], [0, 0, 0]);
+    assert_eq!(BLOCK[0], [-1, -1, -1]);
+    assert_eq!(BLOCK[26], [1, 1, 1]);
+}
+
+#[test]
+fn no_std_macro_in_static() {
+    assert_eq!(LARGE[0], [-2, -2]);
+    assert_eq!(LARGE[23], [2, 2]);
+}
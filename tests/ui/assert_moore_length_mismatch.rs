@@ -0,0 +1,9 @@
+use moore_neighborhood::assert_moore_length;
+
+const RANGE: u32 = 1;
+const DIMENSIONS: usize = 2;
+
+assert_moore_length!(RANGE, DIMENSIONS, 8);
+assert_moore_length!(RANGE, DIMENSIONS, 9);
+
+fn main() {}